@@ -0,0 +1,137 @@
+//! Decoding cost of a 20-year daily adjusted series, JSON versus CSV.
+//!
+//! Run with `cargo bench --bench history_decode`. Prints the body size and the
+//! median decode time of each format.
+
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use borsa_alphavantage::api::time_series::{TimeSeriesResponse, csv_to_history};
+use borsa_alphavantage::window::HistoryWindow;
+use borsa_core::{Currency, IsoCurrency, Range};
+use chrono::{Datelike, NaiveDate, Weekday};
+
+const USD: Currency = Currency::Iso(IsoCurrency::USD);
+const YEARS: i32 = 20;
+const ROUNDS: usize = 30;
+
+struct Row {
+    date: NaiveDate,
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    adjusted: f64,
+    volume: u64,
+}
+
+/// Every weekday of the last `YEARS` years, newest first, as Alpha Vantage orders them.
+fn rows() -> Vec<Row> {
+    let end = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    let start = NaiveDate::from_ymd_opt(2024 - YEARS, 12, 31).unwrap();
+    let mut out = Vec::new();
+    let mut price = 100.0_f64;
+    for (i, date) in end
+        .iter_days()
+        .rev()
+        .take_while(|d| *d > start)
+        .filter(|d| !matches!(d.weekday(), Weekday::Sat | Weekday::Sun))
+        .enumerate()
+    {
+        let swing = f64::from(u32::try_from(i % 17).unwrap()) / 10.0;
+        price = (price + swing - 0.8).max(5.0);
+        out.push(Row {
+            date,
+            open: price,
+            high: price + 1.25,
+            low: price - 1.1,
+            close: price + 0.35,
+            adjusted: (price + 0.35) * 0.97,
+            volume: 1_000_000 + (i as u64 % 9_973) * 113,
+        });
+    }
+    out
+}
+
+fn json_body(rows: &[Row]) -> String {
+    let mut body = String::from(
+        r#"{"Meta Data": {"1. Information": "Daily Time Series with Splits and Dividend Events", "2. Symbol": "BENCH", "5. Time Zone": "US/Eastern"}, "Time Series (Daily)": {"#,
+    );
+    for (i, r) in rows.iter().enumerate() {
+        if i > 0 {
+            body.push(',');
+        }
+        write!(
+            body,
+            r#""{}": {{"1. open": "{:.4}", "2. high": "{:.4}", "3. low": "{:.4}", "4. close": "{:.4}", "5. adjusted close": "{:.4}", "6. volume": "{}", "7. dividend amount": "0.0000", "8. split coefficient": "1.0"}}"#,
+            r.date, r.open, r.high, r.low, r.close, r.adjusted, r.volume
+        )
+        .unwrap();
+    }
+    body.push_str("}}");
+    body
+}
+
+fn csv_body(rows: &[Row]) -> String {
+    let mut body = String::from(
+        "timestamp,open,high,low,close,adjusted_close,volume,dividend_amount,split_coefficient\r\n",
+    );
+    for r in rows {
+        writeln!(
+            body,
+            "{},{:.4},{:.4},{:.4},{:.4},{:.4},{},0.0000,1.0\r",
+            r.date, r.open, r.high, r.low, r.close, r.adjusted, r.volume
+        )
+        .unwrap();
+    }
+    body
+}
+
+fn median(mut f: impl FnMut()) -> Duration {
+    f();
+    let mut samples: Vec<Duration> = (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+    samples.sort();
+    samples[ROUNDS / 2]
+}
+
+fn main() {
+    let rows = rows();
+    let (json, csv) = (json_body(&rows), csv_body(&rows));
+    let window = HistoryWindow::Range(Range::Max);
+
+    let json_time = median(|| {
+        let ts: TimeSeriesResponse = serde_json::from_str(black_box(&json)).unwrap();
+        black_box(ts.to_history(&USD, &window).unwrap());
+    });
+    let csv_time = median(|| {
+        black_box(csv_to_history(black_box(&csv), "US/Eastern", &USD, &window).unwrap());
+    });
+
+    println!("{} daily bars over {YEARS} years", rows.len());
+    println!(
+        "json: {:>9} bytes  {:>10.3?} per decode",
+        json.len(),
+        json_time
+    );
+    println!(
+        "csv:  {:>9} bytes  {:>10.3?} per decode",
+        csv.len(),
+        csv_time
+    );
+    println!(
+        "csv is {:.1}x smaller and {:.1}x faster",
+        bytes_ratio(json.len(), csv.len()),
+        json_time.as_secs_f64() / csv_time.as_secs_f64()
+    );
+}
+
+fn bytes_ratio(a: usize, b: usize) -> f64 {
+    f64::from(u32::try_from(a).unwrap()) / f64::from(u32::try_from(b).unwrap())
+}
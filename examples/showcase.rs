@@ -4,9 +4,7 @@ use borsa_alphavantage::AvConnector;
 use borsa_core::{AssetKind, HistoryRequest, Instrument, Interval, Range, SearchRequest};
 
 // Bring provider traits into scope for method call syntax
-use borsa_core::connector::{
-    /*EarningsProvider,*/ HistoryProvider, QuoteProvider, SearchProvider,
-};
+use borsa_core::connector::{EarningsProvider, HistoryProvider, QuoteProvider, SearchProvider};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
@@ -68,10 +66,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
         println!("Last close {} at {}", last.close, last.ts);
     }
 
-    // Not working as expected, returns an error from the underlying crate.
-
     // --- Earnings example (AAPL) ---
-    /*
     println!("--- Earnings example (AAPL) ---");
     let earnings = av.earnings(&aapl).await?;
     println!(
@@ -79,7 +74,6 @@ async fn main() -> Result<(), Box<dyn Error>> {
         earnings.yearly.len(),
         earnings.quarterly_eps.len()
     );
-    */
 
     Ok(())
 }
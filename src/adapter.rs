@@ -1,24 +1,63 @@
+#[cfg(feature = "test-adapters")]
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
+use borsa_core::Currency;
 use borsa_core::{
-    BorsaError,
-    /*Earnings, EarningsQuarter, EarningsQuarterEps, EarningsYear,*/ HistoryRequest,
-    HistoryResponse, Quote, SearchRequest, SearchResult, Symbol,
+    Action, BalanceSheetRow, BorsaError, CashflowRow, Earnings, ExchangeRate, HistoryRequest,
+    HistoryResponse, IncomeStatementRow, Profile, Quote, SearchRequest, SearchResult, Symbol,
 };
-use borsa_core::{Currency, Money};
+use chrono::Utc;
+use serde::de::DeserializeOwned;
 
-use crate::convert::{
-    map_crypto_to_history, map_forex_to_history, map_kind_from_search_type,
-    map_timeseries_to_history,
+use crate::api::{
+    self,
+    bulk_quotes::BulkQuotesResponse,
+    dividends::{Dividend, DividendsResponse},
+    earnings::EarningsResponse,
+    exchange_rate::ExchangeRateResponse,
+    overview::OverviewResponse,
+    quote::GlobalQuoteResponse,
+    search::SearchResponse,
+    splits::SplitsResponse,
+    statements::{
+        BalanceSheetReport, CashFlowReport, IncomeStatementReport, IncomeStatementResponse,
+        StatementResponse,
+    },
+    time_series::{TimeSeriesResponse, csv_to_history},
 };
-use alpha_vantage as av;
+use crate::builder::{Datatype, Entitlement};
+use crate::convert::{map_kind_from_search_type, parse_currency};
+use crate::rate_limit::RateLimiter;
+use crate::resample;
+use crate::retry::RetryPolicy;
+use crate::window::{DEFAULT_MAX_INTRADAY_MONTHS, HistoryWindow};
+
+const USD: Currency = Currency::Iso(borsa_core::IsoCurrency::USD);
+/// Zone of US equity bars; CSV bodies do not state it.
+const US_EASTERN: &str = "US/Eastern";
+/// Zone of forex and crypto bars.
+const UTC: &str = "UTC";
 
 /// Quotes adapter abstraction wrapping Alpha Vantage quote endpoint(s).
 #[async_trait]
 pub trait AvQuotes: Send + Sync {
     /// Fetch a single equity quote by symbol.
     async fn quote_equity(&self, symbol: &str) -> Result<Quote, BorsaError>;
+    /// Fetch a realtime exchange-rate quote for `from` priced in `to`
+    /// (physical or digital currencies).
+    async fn quote_pair(&self, from: &str, to: &str) -> Result<Quote, BorsaError>;
+    /// Fetch the realtime rate for `from` in `to` without rounding it to the
+    /// minor units of `to`.
+    async fn exchange_rate(&self, from: &str, to: &str) -> Result<ExchangeRate, BorsaError>;
+    /// Fetch quotes for up to 100 US equity symbols in one request, one entry
+    /// per returned row keyed by the echoed symbol. Symbols the provider does
+    /// not know are left out; a malformed row fails only its own entry.
+    async fn quote_bulk(
+        &self,
+        symbols: &[&str],
+    ) -> Result<Vec<(String, Result<Quote, BorsaError>)>, BorsaError>;
 }
 
 /// History adapter abstraction wrapping Alpha Vantage time-series endpoints.
@@ -37,10 +76,11 @@ pub trait AvHistory: Send + Sync {
         quote: &str,
         req: &HistoryRequest,
     ) -> Result<HistoryResponse, BorsaError>;
-    /// Fetch crypto OHLCV history.
+    /// Fetch crypto OHLCV history for `base` priced in the `market` currency.
     async fn crypto(
         &self,
-        symbol: &str,
+        base: &str,
+        market: &str,
         req: &HistoryRequest,
     ) -> Result<HistoryResponse, BorsaError>;
 }
@@ -52,60 +92,308 @@ pub trait AvSearch: Send + Sync {
     async fn search(&self, req: &SearchRequest) -> Result<Vec<SearchResult>, BorsaError>;
 }
 
-/*
 /// Fundamentals adapter for fetching earnings data.
-/// This unfortunately returns an error from the underlying crate, seems to be a bug in the crate.
 #[async_trait]
 pub trait AvEarnings: Send + Sync {
     /// Fetch earnings for the provided symbol.
     async fn earnings(&self, symbol: &str) -> Result<Earnings, BorsaError>;
 }
-*/
 
-/// Production adapter that owns an `alpha_vantage::ApiClient`.
+/// Fundamentals adapter for the financial statement endpoints.
+#[async_trait]
+pub trait AvFundamentals: Send + Sync {
+    /// Fetch annual or quarterly income statements, most recent first.
+    async fn income_statement(
+        &self,
+        symbol: &str,
+        quarterly: bool,
+    ) -> Result<Vec<IncomeStatementRow>, BorsaError>;
+    /// Fetch annual or quarterly balance sheets, most recent first.
+    async fn balance_sheet(
+        &self,
+        symbol: &str,
+        quarterly: bool,
+    ) -> Result<Vec<BalanceSheetRow>, BorsaError>;
+    /// Fetch annual or quarterly cash flow statements, most recent first.
+    async fn cashflow(&self, symbol: &str, quarterly: bool)
+    -> Result<Vec<CashflowRow>, BorsaError>;
+}
+
+/// Profile adapter wrapping the Alpha Vantage company overview.
+#[async_trait]
+pub trait AvProfile: Send + Sync {
+    /// Fetch the company or fund profile for the provided symbol.
+    async fn profile(&self, symbol: &str) -> Result<Profile, BorsaError>;
+}
+
+/// Corporate actions adapter wrapping the Alpha Vantage dividend and split history.
+#[async_trait]
+pub trait AvCorporateActions: Send + Sync {
+    /// Fetch every dividend on record for the provided symbol, most recent first.
+    async fn dividends(&self, symbol: &str) -> Result<Vec<Dividend>, BorsaError>;
+    /// Fetch every split on record for the provided symbol as `Action::Split`
+    /// entries, most recent first.
+    async fn splits(&self, symbol: &str) -> Result<Vec<Action>, BorsaError>;
+}
+
+/// Production adapter issuing requests through the native [`api`] layer.
 #[derive(Clone)]
 pub struct RealAdapter {
-    client: Arc<av::ApiClient>,
+    api: api::Client,
+    earnings_income_statement: bool,
+    max_intraday_months: usize,
 }
 
 impl RealAdapter {
+    /// Client honouring the system proxy, like the builder's `ProxyMode::System` default.
     fn make_default_client() -> reqwest::Client {
         reqwest::Client::builder()
-            .no_proxy()
             .build()
-            .expect("failed to build reqwest client without system proxy")
+            .expect("failed to build reqwest client")
     }
 
     /// Build using the native Alpha Vantage API key and an internal client.
     pub fn new_with_key(key: impl Into<String>) -> Self {
-        let client = Self::make_default_client();
-        let api = av::set_api(key, client);
-        Self {
-            client: Arc::new(api),
-        }
+        Self::new_with_key_and_client(key, Self::make_default_client())
     }
     /// Build using a `RapidAPI` key for Alpha Vantage and an internal client.
     pub fn new_with_rapidapi(key: impl Into<String>) -> Self {
-        let client = Self::make_default_client();
-        let api = av::set_rapid_api(key, client);
-        Self {
-            client: Arc::new(api),
-        }
+        Self::new_with_rapidapi_and_client(key, Self::make_default_client())
     }
 
     /// Build using an external `reqwest::Client` with the native Alpha Vantage API key.
     pub fn new_with_key_and_client(key: impl Into<String>, client: reqwest::Client) -> Self {
-        let api = av::set_api(key, client);
-        Self {
-            client: Arc::new(api),
-        }
+        Self::from_auth(api::Auth::Key(key.into()), client)
     }
 
     /// Build using an external `reqwest::Client` with a `RapidAPI` key for Alpha Vantage.
     pub fn new_with_rapidapi_and_client(key: impl Into<String>, client: reqwest::Client) -> Self {
-        let api = av::set_rapid_api(key, client);
+        Self::from_auth(api::Auth::RapidApi(key.into()), client)
+    }
+
+    pub(crate) fn from_auth(auth: api::Auth, http: reqwest::Client) -> Self {
         Self {
-            client: Arc::new(api),
+            api: api::Client::new(http, auth),
+            earnings_income_statement: false,
+            max_intraday_months: DEFAULT_MAX_INTRADAY_MONTHS,
+        }
+    }
+
+    /// Send every request to `url` (the full `query` endpoint) instead of the Alpha Vantage host.
+    ///
+    /// Useful for a local stand-in server in tests or an internal caching proxy.
+    #[must_use]
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.api.set_base_url(url.into());
+        self
+    }
+
+    /// Abort each HTTP attempt after `timeout`; timed-out attempts are retried.
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.api.set_timeout(timeout);
+        self
+    }
+
+    /// Request realtime or delayed US market data on endpoints that accept `entitlement`.
+    #[must_use]
+    pub const fn with_entitlement(mut self, entitlement: Entitlement) -> Self {
+        self.api.set_entitlement(entitlement);
+        self
+    }
+
+    /// Request history series as `datatype` instead of JSON.
+    #[must_use]
+    pub const fn with_datatype(mut self, datatype: Datatype) -> Self {
+        self.api.set_datatype(datatype);
+        self
+    }
+
+    /// Schedule every request through `limiter` instead of sending requests unthrottled.
+    ///
+    /// Clones made afterwards share the limiter and therefore the budget.
+    #[must_use]
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.api.set_rate_limiter(limiter);
+        self
+    }
+
+    /// Retry transient failures according to `policy` instead of the default.
+    #[must_use]
+    pub const fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.api.set_retry_policy(policy);
+        self
+    }
+
+    /// Also request `INCOME_STATEMENT` for earnings and fill yearly and
+    /// quarterly revenue and earnings from it, at one extra request per call.
+    #[must_use]
+    pub const fn with_earnings_income_statement(mut self, enabled: bool) -> Self {
+        self.earnings_income_statement = enabled;
+        self
+    }
+
+    /// Allow intraday equity history to fan out to at most `months` monthly
+    /// slices, one request each (default: [`DEFAULT_MAX_INTRADAY_MONTHS`]).
+    #[must_use]
+    pub const fn with_max_intraday_months(mut self, months: usize) -> Self {
+        self.max_intraday_months = months;
+        self
+    }
+
+    /// Fetch intraday equity bars, fanning out into `month` slices for historical windows.
+    async fn equity_intraday(
+        &self,
+        symbol: &str,
+        req: &HistoryRequest,
+    ) -> Result<HistoryResponse, BorsaError> {
+        let interval = Self::intraday_interval(req)?;
+        let adjusted = if req.auto_adjust() { "true" } else { "false" };
+        let extended_hours = if req.include_prepost() {
+            "true"
+        } else {
+            "false"
+        };
+        let params = [
+            ("symbol", symbol),
+            ("interval", interval),
+            ("adjusted", adjusted),
+            ("extended_hours", extended_hours),
+        ];
+        let window = HistoryWindow::from_request(req);
+        let months = window.intraday_months(chrono::Utc::now(), self.max_intraday_months)?;
+
+        if months.is_empty() {
+            let output_size = Self::output_size(&window, req);
+            return self
+                .series(
+                    "TIME_SERIES_INTRADAY",
+                    &[&params[..], &[("outputsize", output_size)]].concat(),
+                    US_EASTERN,
+                    &USD,
+                    &window,
+                )
+                .await;
+        }
+
+        // Each slice is fetched in full and merged; the window is applied once at the end.
+        let mut slices = Vec::with_capacity(months.len());
+        for month in &months {
+            let slice = self
+                .series(
+                    "TIME_SERIES_INTRADAY",
+                    &[
+                        &params[..],
+                        &[("month", month.as_str()), ("outputsize", "full")],
+                    ]
+                    .concat(),
+                    US_EASTERN,
+                    &USD,
+                    &HistoryWindow::Range(borsa_core::Range::Max),
+                )
+                .await?;
+            slices.push(slice);
+        }
+        let mut merged = borsa_core::merge_history(slices)?;
+        window.trim(&mut merged);
+        Ok(merged)
+    }
+
+    /// Fetch intraday crypto bars from `CRYPTO_INTRADAY`.
+    async fn crypto_intraday(
+        &self,
+        base: &str,
+        market: &str,
+        req: &HistoryRequest,
+    ) -> Result<HistoryResponse, BorsaError> {
+        let interval = Self::intraday_interval(req)?;
+        let window = HistoryWindow::from_request(req);
+        let output_size = Self::output_size(&window, req);
+        self.series(
+            "CRYPTO_INTRADAY",
+            &[
+                ("symbol", base),
+                ("market", market),
+                ("interval", interval),
+                ("outputsize", output_size),
+            ],
+            UTC,
+            &parse_currency(market)?,
+            &window,
+        )
+        .await
+    }
+
+    /// Fetch a time series in the configured datatype, priced in `currency`
+    /// and trimmed to `window`.
+    ///
+    /// `time_zone` is only used for CSV bodies; JSON series state their own.
+    async fn series(
+        &self,
+        function: &str,
+        params: &[(&str, &str)],
+        time_zone: &str,
+        currency: &Currency,
+        window: &HistoryWindow,
+    ) -> Result<HistoryResponse, BorsaError> {
+        match self.api.datatype() {
+            Datatype::Json => {
+                let ts: TimeSeriesResponse = self.api.get_json(function, params).await?;
+                ts.to_history(currency, window)
+            }
+            Datatype::Csv => {
+                let body = self.api.get_csv(function, params).await?;
+                csv_to_history(&body, time_zone, currency, window)
+            }
+        }
+    }
+
+    /// Alpha Vantage `interval` parameter for an intraday request.
+    fn intraday_interval(req: &HistoryRequest) -> Result<&'static str, BorsaError> {
+        match req.interval().minutes() {
+            Some(1) => Ok("1min"),
+            Some(5) => Ok("5min"),
+            Some(15) => Ok("15min"),
+            Some(30) => Ok("30min"),
+            Some(60) => Ok("60min"),
+            _ => Err(BorsaError::unsupported(
+                "intraday interval for Alpha Vantage",
+            )),
+        }
+    }
+
+    /// Window a series is trimmed to when decoded.
+    ///
+    /// Quarterly requests keep the whole monthly series so that a window
+    /// starting mid-quarter still resamples whole quarters.
+    fn series_window(req: &HistoryRequest) -> HistoryWindow {
+        if req.interval() == borsa_core::Interval::M3 {
+            HistoryWindow::Range(borsa_core::Range::Max)
+        } else {
+            HistoryWindow::from_request(req)
+        }
+    }
+
+    /// Build quarterly bars from a monthly series when the request asked for
+    /// `M3`, then trim them to the requested window.
+    fn resample_if_quarterly(
+        resp: HistoryResponse,
+        req: &HistoryRequest,
+    ) -> Result<HistoryResponse, BorsaError> {
+        if req.interval() != borsa_core::Interval::M3 {
+            return Ok(resp);
+        }
+        let mut quarterly = resample::monthly_to_quarterly(resp)?;
+        HistoryWindow::from_request(req).trim(&mut quarterly);
+        Ok(quarterly)
+    }
+
+    /// Ask for `compact` output when the latest 100 points cover the requested window.
+    fn output_size(window: &HistoryWindow, req: &HistoryRequest) -> &'static str {
+        if window.fits_compact(req.interval(), chrono::Utc::now()) {
+            "compact"
+        } else {
+            "full"
         }
     }
 }
@@ -113,31 +401,51 @@ impl RealAdapter {
 #[async_trait]
 impl AvQuotes for RealAdapter {
     async fn quote_equity(&self, symbol: &str) -> Result<Quote, BorsaError> {
-        let q = self
-            .client
-            .quote(symbol)
-            .json()
-            .await
-            .map_err(|e| BorsaError::connector("borsa-alphavantage", e.to_string()))?;
-
-        let sym = q.symbol().to_string();
-        if sym.is_empty() {
+        let q: GlobalQuoteResponse = self
+            .api
+            .get_json("GLOBAL_QUOTE", &[("symbol", symbol)])
+            .await?;
+        if q.is_empty() {
             return Err(BorsaError::not_found(format!("quote for {symbol}")));
         }
-        let symbol = Symbol::new(&sym)
-            .map_err(|e| BorsaError::Data(format!("invalid symbol '{sym}': {e}")))?;
-        let to_money = |v: f64| {
-            Money::from_canonical_str(&v.to_string(), Currency::Iso(borsa_core::IsoCurrency::USD))
-                .unwrap()
-        };
-        Ok(Quote {
-            symbol,
-            shortname: None,
-            price: Some(to_money(q.price())),
-            previous_close: Some(to_money(q.previous())),
-            exchange: None,
-            market_state: None,
-        })
+        q.to_quote()
+    }
+
+    async fn quote_pair(&self, from: &str, to: &str) -> Result<Quote, BorsaError> {
+        let r: ExchangeRateResponse = self
+            .api
+            .get_json(
+                "CURRENCY_EXCHANGE_RATE",
+                &[("from_currency", from), ("to_currency", to)],
+            )
+            .await?;
+        r.to_quote(&format!("{from}/{to}"))
+    }
+
+    async fn exchange_rate(&self, from: &str, to: &str) -> Result<ExchangeRate, BorsaError> {
+        let r: ExchangeRateResponse = self
+            .api
+            .get_json(
+                "CURRENCY_EXCHANGE_RATE",
+                &[("from_currency", from), ("to_currency", to)],
+            )
+            .await?;
+        r.to_exchange_rate(&format!("{from}/{to}"))
+    }
+
+    async fn quote_bulk(
+        &self,
+        symbols: &[&str],
+    ) -> Result<Vec<(String, Result<Quote, BorsaError>)>, BorsaError> {
+        let r: BulkQuotesResponse = self
+            .api
+            .get_json("REALTIME_BULK_QUOTES", &[("symbol", &symbols.join(","))])
+            .await?;
+        let now = Utc::now();
+        Ok(r.rows()
+            .into_iter()
+            .map(|(symbol, row)| (symbol, row.and_then(|q| q.to_quote_at(now))))
+            .collect())
     }
 }
 
@@ -148,61 +456,36 @@ impl AvHistory for RealAdapter {
         symbol: &str,
         req: &HistoryRequest,
     ) -> Result<HistoryResponse, BorsaError> {
-        use av::api::{OutputSize, TimeSeriesInterval as A};
-        use av::stock_time::StockFunction as SF;
-
-        let (func, interval) = match req.interval() {
-            i if i.is_intraday() => {
-                let iv = match i.minutes() {
-                    Some(1) => A::OneMin,
-                    Some(5) => A::FiveMin,
-                    Some(15) => A::FifteenMin,
-                    Some(30) => A::ThirtyMin,
-                    Some(60) => A::SixtyMin,
-                    _ => {
-                        return Err(BorsaError::unsupported(
-                            "intraday interval for Alpha Vantage",
-                        ));
-                    }
-                };
-                (SF::IntraDay, Some(iv))
-            }
-            borsa_core::Interval::D1 => {
-                if req.auto_adjust() {
-                    (SF::DailyAdjusted, None)
-                } else {
-                    (SF::Daily, None)
-                }
-            }
-            borsa_core::Interval::W1 => {
-                if req.auto_adjust() {
-                    (SF::WeeklyAdjusted, None)
-                } else {
-                    (SF::Weekly, None)
-                }
-            }
-            borsa_core::Interval::M1 => {
-                if req.auto_adjust() {
-                    (SF::MonthlyAdjusted, None)
-                } else {
-                    (SF::Monthly, None)
-                }
-            }
-            _ => return Err(BorsaError::unsupported("interval for Alpha Vantage")),
-        };
+        use borsa_core::Interval as I;
 
-        let mut b = self.client.stock_time(func, symbol);
-        if let Some(iv) = interval {
-            b = b.interval(iv);
-            b = b.adjusted(req.auto_adjust());
+        if req.interval().is_intraday() {
+            return self.equity_intraday(symbol, req).await;
         }
-        b = b.output_size(OutputSize::Full);
 
-        let ts = b
-            .json()
-            .await
-            .map_err(|e| BorsaError::connector("borsa-alphavantage", e.to_string()))?;
-        Ok(map_timeseries_to_history(&ts))
+        let function = match (req.interval(), req.auto_adjust()) {
+            (I::D1, true) => "TIME_SERIES_DAILY_ADJUSTED",
+            (I::D1, false) => "TIME_SERIES_DAILY",
+            (I::W1, true) => "TIME_SERIES_WEEKLY_ADJUSTED",
+            (I::W1, false) => "TIME_SERIES_WEEKLY",
+            (I::M1 | I::M3, true) => "TIME_SERIES_MONTHLY_ADJUSTED",
+            (I::M1 | I::M3, false) => "TIME_SERIES_MONTHLY",
+            _ => return Err(BorsaError::unsupported("interval for Alpha Vantage")),
+        };
+
+        let window = HistoryWindow::from_request(req);
+        let resp = self
+            .series(
+                function,
+                &[
+                    ("symbol", symbol),
+                    ("outputsize", Self::output_size(&window, req)),
+                ],
+                US_EASTERN,
+                &USD,
+                &Self::series_window(req),
+            )
+            .await?;
+        Self::resample_if_quarterly(resp, req)
     }
 
     async fn forex(
@@ -211,89 +494,89 @@ impl AvHistory for RealAdapter {
         quote: &str,
         req: &HistoryRequest,
     ) -> Result<HistoryResponse, BorsaError> {
-        use av::api::{OutputSize, TimeSeriesInterval as A};
-        use av::forex::ForexFunction as FF;
-
-        let (func, intr) = match req.interval() {
-            i if i.is_intraday() => {
-                let iv = match i.minutes() {
-                    Some(1) => A::OneMin,
-                    Some(5) => A::FiveMin,
-                    Some(15) => A::FifteenMin,
-                    Some(30) => A::ThirtyMin,
-                    Some(60) => A::SixtyMin,
-                    _ => {
-                        return Err(BorsaError::unsupported(
-                            "intraday interval for Alpha Vantage",
-                        ));
-                    }
-                };
-                (FF::IntraDay, Some(iv))
-            }
-            borsa_core::Interval::D1 => (FF::Daily, None),
-            borsa_core::Interval::W1 => (FF::Weekly, None),
-            borsa_core::Interval::M1 => (FF::Monthly, None),
+        use borsa_core::Interval as I;
+
+        let window = HistoryWindow::from_request(req);
+        let params = [
+            ("from_symbol", base),
+            ("to_symbol", quote),
+            ("outputsize", Self::output_size(&window, req)),
+        ];
+        let (function, params) = match req.interval() {
+            i if i.is_intraday() => (
+                "FX_INTRADAY",
+                [&params[..], &[("interval", Self::intraday_interval(req)?)]].concat(),
+            ),
+            I::D1 => ("FX_DAILY", params.to_vec()),
+            I::W1 => ("FX_WEEKLY", params[..2].to_vec()),
+            I::M1 | I::M3 => ("FX_MONTHLY", params[..2].to_vec()),
             _ => return Err(BorsaError::unsupported("interval for Alpha Vantage")),
         };
-
-        let mut b = self.client.forex(func, base, quote);
-        if let Some(i) = intr {
-            b = b.interval(i);
-        }
-        b = b.output_size(OutputSize::Full);
-
-        let fx = b
-            .json()
-            .await
-            .map_err(|e| BorsaError::connector("borsa-alphavantage", e.to_string()))?;
-        Ok(map_forex_to_history(&fx))
+        let resp = self
+            .series(
+                function,
+                &params,
+                UTC,
+                &parse_currency(quote)?,
+                &Self::series_window(req),
+            )
+            .await?;
+        Self::resample_if_quarterly(resp, req)
     }
 
     async fn crypto(
         &self,
-        symbol: &str,
+        base: &str,
+        market: &str,
         req: &HistoryRequest,
     ) -> Result<HistoryResponse, BorsaError> {
-        use av::crypto::CryptoFunction as CF;
-        let func = match req.interval() {
-            borsa_core::Interval::W1 => CF::Weekly,
-            borsa_core::Interval::M1 | borsa_core::Interval::M3 => CF::Monthly,
-            _ => CF::Daily,
+        use borsa_core::Interval as I;
+
+        let function = match req.interval() {
+            i if i.is_intraday() => return self.crypto_intraday(base, market, req).await,
+            I::D1 => "DIGITAL_CURRENCY_DAILY",
+            I::W1 => "DIGITAL_CURRENCY_WEEKLY",
+            I::M1 | I::M3 => "DIGITAL_CURRENCY_MONTHLY",
+            _ => {
+                return Err(BorsaError::unsupported(
+                    "crypto history interval for Alpha Vantage",
+                ));
+            }
         };
-        let c = self
-            .client
-            .crypto(func, symbol, "USD")
-            .json()
-            .await
-            .map_err(|e| BorsaError::connector("borsa-alphavantage", e.to_string()))?;
-        Ok(map_crypto_to_history(&c))
+        let resp = self
+            .series(
+                function,
+                &[("symbol", base), ("market", market)],
+                UTC,
+                &parse_currency(market)?,
+                &Self::series_window(req),
+            )
+            .await?;
+        Self::resample_if_quarterly(resp, req)
     }
 }
 
 #[async_trait]
 impl AvSearch for RealAdapter {
     async fn search(&self, req: &SearchRequest) -> Result<Vec<SearchResult>, BorsaError> {
-        let result = self
-            .client
-            .search(req.query())
-            .json()
-            .await
-            .map_err(|e| BorsaError::connector("borsa-alphavantage", e.to_string()))?;
+        let result: SearchResponse = self
+            .api
+            .get_json("SYMBOL_SEARCH", &[("keywords", req.query())])
+            .await?;
 
         let mut out: Vec<SearchResult> = Vec::new();
-        for m in result.matches() {
-            let kind = map_kind_from_search_type(m.stock_type());
+        for m in &result.best_matches {
+            let kind = map_kind_from_search_type(&m.kind);
             if let Some(k) = req.kind()
                 && k != kind
             {
                 continue;
             }
             out.push(SearchResult {
-                symbol: Symbol::new(m.symbol()).map_err(|e| {
-                    BorsaError::Data(format!("invalid symbol '{}': {e}", m.symbol()))
-                })?,
-                name: Some(m.name().to_string()),
-                exchange: borsa_core::Exchange::try_from_str(m.region()).ok(),
+                symbol: Symbol::new(&m.symbol)
+                    .map_err(|e| BorsaError::Data(format!("invalid symbol '{}': {e}", m.symbol)))?,
+                name: Some(m.name.clone()),
+                exchange: borsa_core::Exchange::try_from_str(&m.region).ok(),
                 kind,
             });
         }
@@ -308,94 +591,181 @@ impl AvSearch for RealAdapter {
     }
 }
 
-/*
 #[async_trait]
 impl AvEarnings for RealAdapter {
     async fn earnings(&self, symbol: &str) -> Result<Earnings, BorsaError> {
-        let e = self
-            .client
-            .earning(symbol)
-            .json()
-            .await
-            .map_err(|er| BorsaError::connector("borsa-alphavantage", er.to_string()))?;
-
-        let mut yearly: Vec<EarningsYear> = Vec::new();
-        for y in e.annual_earning() {
-            let year = y
-                .fiscal_date_ending()
-                .get(..4)
-                .and_then(|s| s.parse::<i32>().ok())
-                .unwrap_or(0);
-            yearly.push(EarningsYear {
-                year,
-                revenue: None,
-                earnings: None,
-            });
+        let e: EarningsResponse = self.api.get_json("EARNINGS", &[("symbol", symbol)]).await?;
+        if e.is_empty() {
+            return Err(BorsaError::not_found(format!("earnings for {symbol}")));
         }
+        if !self.earnings_income_statement {
+            return Ok(e.to_earnings());
+        }
+        // The income statement only enriches the EPS data; losing it is not an error.
+        let income: Result<IncomeStatementResponse, _> = self
+            .api
+            .get_json("INCOME_STATEMENT", &[("symbol", symbol)])
+            .await;
+        Ok(income.map_or_else(|_| e.to_earnings(), |income| e.to_earnings_with(&income)))
+    }
+}
 
-        let mut quarterly_eps: Vec<EarningsQuarterEps> = Vec::new();
-        let mut quarterly: Vec<EarningsQuarter> = Vec::new();
-        for q in e.quarterly_earning() {
-            use borsa_core::{Currency, Money};
-            let to_money = |v: f64| {
-                Money::from_canonical_str(
-                    &v.to_string(),
-                    Currency::Iso(borsa_core::IsoCurrency::USD),
-                )
-                .unwrap()
-            };
-            quarterly_eps.push(EarningsQuarterEps {
-                period: if q.reported_date().is_empty() {
-                    q.fiscal_date_ending()
-                        .parse::<borsa_core::Period>()
-                        .unwrap_or(borsa_core::Period::Year { year: 0 })
-                } else {
-                    q.reported_date()
-                        .parse::<borsa_core::Period>()
-                        .unwrap_or(borsa_core::Period::Year { year: 0 })
-                },
-                actual: q.reported_eps().map(to_money),
-                estimate: Some(to_money(q.estimated_eps())),
-            });
-            quarterly.push(EarningsQuarter {
-                period: q
-                    .fiscal_date_ending()
-                    .parse::<borsa_core::Period>()
-                    .unwrap_or(borsa_core::Period::Year { year: 0 }),
-                revenue: None,
-                earnings: None,
-            });
+impl RealAdapter {
+    /// Fetch one statement endpoint and map the requested reports.
+    async fn statement<R, T>(
+        &self,
+        function: &str,
+        what: &str,
+        symbol: &str,
+        quarterly: bool,
+        to_row: fn(&R) -> Result<T, BorsaError>,
+    ) -> Result<Vec<T>, BorsaError>
+    where
+        R: DeserializeOwned + Send,
+    {
+        let r: StatementResponse<R> = self.api.get_json(function, &[("symbol", symbol)]).await?;
+        if r.is_empty() {
+            return Err(BorsaError::not_found(format!("{what} for {symbol}")));
         }
+        r.reports(quarterly).iter().map(to_row).collect()
+    }
+}
+
+#[async_trait]
+impl AvFundamentals for RealAdapter {
+    async fn income_statement(
+        &self,
+        symbol: &str,
+        quarterly: bool,
+    ) -> Result<Vec<IncomeStatementRow>, BorsaError> {
+        self.statement(
+            "INCOME_STATEMENT",
+            "income statement",
+            symbol,
+            quarterly,
+            IncomeStatementReport::to_row,
+        )
+        .await
+    }
 
-        Ok(Earnings {
-            yearly,
+    async fn balance_sheet(
+        &self,
+        symbol: &str,
+        quarterly: bool,
+    ) -> Result<Vec<BalanceSheetRow>, BorsaError> {
+        self.statement(
+            "BALANCE_SHEET",
+            "balance sheet",
+            symbol,
             quarterly,
-            quarterly_eps,
-        })
+            BalanceSheetReport::to_row,
+        )
+        .await
+    }
+
+    async fn cashflow(
+        &self,
+        symbol: &str,
+        quarterly: bool,
+    ) -> Result<Vec<CashflowRow>, BorsaError> {
+        self.statement(
+            "CASH_FLOW",
+            "cash flow",
+            symbol,
+            quarterly,
+            CashFlowReport::to_row,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl AvProfile for RealAdapter {
+    async fn profile(&self, symbol: &str) -> Result<Profile, BorsaError> {
+        let o: OverviewResponse = self.api.get_json("OVERVIEW", &[("symbol", symbol)]).await?;
+        if o.is_empty() {
+            return Err(BorsaError::not_found(format!("profile for {symbol}")));
+        }
+        o.to_profile()
+    }
+}
+
+#[async_trait]
+impl AvCorporateActions for RealAdapter {
+    async fn dividends(&self, symbol: &str) -> Result<Vec<Dividend>, BorsaError> {
+        let r: DividendsResponse = self
+            .api
+            .get_json("DIVIDENDS", &[("symbol", symbol)])
+            .await?;
+        Ok(r.to_dividends())
+    }
+
+    async fn splits(&self, symbol: &str) -> Result<Vec<Action>, BorsaError> {
+        let r: SplitsResponse = self.api.get_json("SPLITS", &[("symbol", symbol)]).await?;
+        r.to_actions()
     }
 }
-*/
 
 /* -------- Test-only lightweight adapter constructors ------- */
 
 #[cfg(feature = "test-adapters")]
 impl dyn AvQuotes {
-    /// Build an `AvQuotes` from a closure (tests only).
+    /// Build an `AvQuotes` serving equity quotes from a closure (tests only).
     pub fn from_fn<F>(f: F) -> Arc<dyn AvQuotes>
     where
         F: Send + Sync + 'static + Fn(String) -> Result<Quote, BorsaError>,
     {
-        struct FnQuotes<F>(F);
+        <dyn AvQuotes>::from_fns(f, |_f, _t| Err(BorsaError::unsupported("quote/pair")))
+    }
+
+    /// Build an `AvQuotes` from equity and currency-pair closures (tests only).
+    ///
+    /// Bulk quotes call the equity closure per symbol and leave out symbols
+    /// it reports as `NotFound`.
+    pub fn from_fns<FE, FP>(fe: FE, fp: FP) -> Arc<dyn AvQuotes>
+    where
+        FE: Send + Sync + 'static + Fn(String) -> Result<Quote, BorsaError>,
+        FP: Send + Sync + 'static + Fn(String, String) -> Result<Quote, BorsaError>,
+    {
+        struct FnQuotes<FE, FP> {
+            fe: FE,
+            fp: FP,
+        }
         #[async_trait]
-        impl<F> AvQuotes for FnQuotes<F>
+        impl<FE, FP> AvQuotes for FnQuotes<FE, FP>
         where
-            F: Send + Sync + 'static + Fn(String) -> Result<Quote, BorsaError>,
+            FE: Send + Sync + 'static + Fn(String) -> Result<Quote, BorsaError>,
+            FP: Send + Sync + 'static + Fn(String, String) -> Result<Quote, BorsaError>,
         {
             async fn quote_equity(&self, symbol: &str) -> Result<Quote, BorsaError> {
-                (self.0)(symbol.to_string())
+                (self.fe)(symbol.to_string())
+            }
+            async fn quote_pair(&self, from: &str, to: &str) -> Result<Quote, BorsaError> {
+                (self.fp)(from.to_string(), to.to_string())
+            }
+            async fn exchange_rate(
+                &self,
+                _from: &str,
+                _to: &str,
+            ) -> Result<ExchangeRate, BorsaError> {
+                Err(BorsaError::unsupported("exchange_rate"))
+            }
+            async fn quote_bulk(
+                &self,
+                symbols: &[&str],
+            ) -> Result<Vec<(String, Result<Quote, BorsaError>)>, BorsaError> {
+                let mut quotes = Vec::with_capacity(symbols.len());
+                for s in symbols {
+                    match (self.fe)((*s).to_string()) {
+                        Ok(q) => quotes.push(((*s).to_string(), Ok(q))),
+                        Err(BorsaError::NotFound { .. }) => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+                Ok(quotes)
             }
         }
-        Arc::new(FnQuotes(f))
+        Arc::new(FnQuotes { fe, fp })
     }
 }
 
@@ -415,7 +785,7 @@ impl dyn AvHistory {
         FC: Send
             + Sync
             + 'static
-            + Fn(String, HistoryRequest) -> Result<HistoryResponse, BorsaError>,
+            + Fn(String, String, HistoryRequest) -> Result<HistoryResponse, BorsaError>,
     {
         struct FnHistory<FE, FFx, FC> {
             fe: FE,
@@ -436,7 +806,7 @@ impl dyn AvHistory {
             FC: Send
                 + Sync
                 + 'static
-                + Fn(String, HistoryRequest) -> Result<HistoryResponse, BorsaError>,
+                + Fn(String, String, HistoryRequest) -> Result<HistoryResponse, BorsaError>,
         {
             async fn equity(
                 &self,
@@ -455,10 +825,11 @@ impl dyn AvHistory {
             }
             async fn crypto(
                 &self,
-                symbol: &str,
+                base: &str,
+                market: &str,
                 req: &HistoryRequest,
             ) -> Result<HistoryResponse, BorsaError> {
-                (self.fc)(symbol.to_string(), req.clone())
+                (self.fc)(base.to_string(), market.to_string(), req.clone())
             }
         }
         Arc::new(FnHistory { fe, ffx, fc })
@@ -486,7 +857,6 @@ impl dyn AvSearch {
     }
 }
 
-/*
 #[cfg(feature = "test-adapters")]
 impl dyn AvEarnings {
     /// Build an `AvEarnings` from a closure (tests only).
@@ -507,7 +877,118 @@ impl dyn AvEarnings {
         Arc::new(FnEarnings(f))
     }
 }
-*/
+
+#[cfg(feature = "test-adapters")]
+impl dyn AvFundamentals {
+    /// Build an `AvFundamentals` from per-statement closures receiving
+    /// `(symbol, quarterly)` (tests only).
+    pub fn from_fns<FI, FB, FC>(fi: FI, fb: FB, fc: FC) -> Arc<dyn AvFundamentals>
+    where
+        FI: Send + Sync + 'static + Fn(String, bool) -> Result<Vec<IncomeStatementRow>, BorsaError>,
+        FB: Send + Sync + 'static + Fn(String, bool) -> Result<Vec<BalanceSheetRow>, BorsaError>,
+        FC: Send + Sync + 'static + Fn(String, bool) -> Result<Vec<CashflowRow>, BorsaError>,
+    {
+        struct FnFundamentals<FI, FB, FC> {
+            fi: FI,
+            fb: FB,
+            fc: FC,
+        }
+        #[async_trait]
+        impl<FI, FB, FC> AvFundamentals for FnFundamentals<FI, FB, FC>
+        where
+            FI: Send
+                + Sync
+                + 'static
+                + Fn(String, bool) -> Result<Vec<IncomeStatementRow>, BorsaError>,
+            FB: Send
+                + Sync
+                + 'static
+                + Fn(String, bool) -> Result<Vec<BalanceSheetRow>, BorsaError>,
+            FC: Send + Sync + 'static + Fn(String, bool) -> Result<Vec<CashflowRow>, BorsaError>,
+        {
+            async fn income_statement(
+                &self,
+                symbol: &str,
+                quarterly: bool,
+            ) -> Result<Vec<IncomeStatementRow>, BorsaError> {
+                (self.fi)(symbol.to_string(), quarterly)
+            }
+            async fn balance_sheet(
+                &self,
+                symbol: &str,
+                quarterly: bool,
+            ) -> Result<Vec<BalanceSheetRow>, BorsaError> {
+                (self.fb)(symbol.to_string(), quarterly)
+            }
+            async fn cashflow(
+                &self,
+                symbol: &str,
+                quarterly: bool,
+            ) -> Result<Vec<CashflowRow>, BorsaError> {
+                (self.fc)(symbol.to_string(), quarterly)
+            }
+        }
+        Arc::new(FnFundamentals { fi, fb, fc })
+    }
+}
+
+#[cfg(feature = "test-adapters")]
+impl dyn AvProfile {
+    /// Build an `AvProfile` from a closure (tests only).
+    pub fn from_fn<F>(f: F) -> Arc<dyn AvProfile>
+    where
+        F: Send + Sync + 'static + Fn(String) -> Result<Profile, BorsaError>,
+    {
+        struct FnProfile<F>(F);
+        #[async_trait]
+        impl<F> AvProfile for FnProfile<F>
+        where
+            F: Send + Sync + 'static + Fn(String) -> Result<Profile, BorsaError>,
+        {
+            async fn profile(&self, symbol: &str) -> Result<Profile, BorsaError> {
+                (self.0)(symbol.to_string())
+            }
+        }
+        Arc::new(FnProfile(f))
+    }
+}
+
+#[cfg(feature = "test-adapters")]
+impl dyn AvCorporateActions {
+    /// Build an `AvCorporateActions` serving dividends from a closure (tests only).
+    pub fn from_fn<F>(f: F) -> Arc<dyn AvCorporateActions>
+    where
+        F: Send + Sync + 'static + Fn(String) -> Result<Vec<Dividend>, BorsaError>,
+    {
+        <dyn AvCorporateActions>::from_fns(f, |_s| Err(BorsaError::unsupported("splits")))
+    }
+
+    /// Build an `AvCorporateActions` from dividend and split closures (tests only).
+    pub fn from_fns<FD, FS>(fd: FD, fs: FS) -> Arc<dyn AvCorporateActions>
+    where
+        FD: Send + Sync + 'static + Fn(String) -> Result<Vec<Dividend>, BorsaError>,
+        FS: Send + Sync + 'static + Fn(String) -> Result<Vec<Action>, BorsaError>,
+    {
+        struct FnActions<FD, FS> {
+            fd: FD,
+            fs: FS,
+        }
+        #[async_trait]
+        impl<FD, FS> AvCorporateActions for FnActions<FD, FS>
+        where
+            FD: Send + Sync + 'static + Fn(String) -> Result<Vec<Dividend>, BorsaError>,
+            FS: Send + Sync + 'static + Fn(String) -> Result<Vec<Action>, BorsaError>,
+        {
+            async fn dividends(&self, symbol: &str) -> Result<Vec<Dividend>, BorsaError> {
+                (self.fd)(symbol.to_string())
+            }
+            async fn splits(&self, symbol: &str) -> Result<Vec<Action>, BorsaError> {
+                (self.fs)(symbol.to_string())
+            }
+        }
+        Arc::new(FnActions { fd, fs })
+    }
+}
 
 /// Helper trait to split a concrete adapter into arc trait objects.
 #[cfg(feature = "test-adapters")]
@@ -521,19 +1002,36 @@ pub trait CloneArcAdapters {
         <dyn AvHistory>::from_fns(
             |_s, _r| Err(BorsaError::unsupported("history/equity")),
             |_b, _q, _r| Err(BorsaError::unsupported("history/forex")),
-            |_s, _r| Err(BorsaError::unsupported("history/crypto")),
+            |_b, _m, _r| Err(BorsaError::unsupported("history/crypto")),
         )
     }
     /// Clone as `Arc<dyn AvSearch>`.
     fn clone_arc_search(&self) -> Arc<dyn AvSearch> {
         <dyn AvSearch>::from_fn(|_r| Err(BorsaError::unsupported("search")))
     }
-    /*
     /// Clone as `Arc<dyn AvEarnings>`.
     fn clone_arc_earnings(&self) -> Arc<dyn AvEarnings> {
         <dyn AvEarnings>::from_fn(|_s| Err(BorsaError::unsupported("fundamentals/earnings")))
     }
-    */
+    /// Clone as `Arc<dyn AvFundamentals>`.
+    fn clone_arc_fundamentals(&self) -> Arc<dyn AvFundamentals> {
+        <dyn AvFundamentals>::from_fns(
+            |_s, _q| Err(BorsaError::unsupported("fundamentals/income_statement")),
+            |_s, _q| Err(BorsaError::unsupported("fundamentals/balance_sheet")),
+            |_s, _q| Err(BorsaError::unsupported("fundamentals/cashflow")),
+        )
+    }
+    /// Clone as `Arc<dyn AvProfile>`.
+    fn clone_arc_profile(&self) -> Arc<dyn AvProfile> {
+        <dyn AvProfile>::from_fn(|_s| Err(BorsaError::unsupported("profile")))
+    }
+    /// Clone as `Arc<dyn AvCorporateActions>`.
+    fn clone_arc_actions(&self) -> Arc<dyn AvCorporateActions> {
+        <dyn AvCorporateActions>::from_fns(
+            |_s| Err(BorsaError::unsupported("dividends")),
+            |_s| Err(BorsaError::unsupported("splits")),
+        )
+    }
 }
 
 #[cfg(feature = "test-adapters")]
@@ -550,10 +1048,20 @@ impl CloneArcAdapters for RealAdapter {
     fn clone_arc_search(&self) -> Arc<dyn AvSearch> {
         Arc::new(self.clone()) as Arc<dyn AvSearch>
     }
-    /*
     /// Clone as `Arc<dyn AvEarnings>`.
     fn clone_arc_earnings(&self) -> Arc<dyn AvEarnings> {
         Arc::new(self.clone()) as Arc<dyn AvEarnings>
     }
-    */
+    /// Clone as `Arc<dyn AvFundamentals>`.
+    fn clone_arc_fundamentals(&self) -> Arc<dyn AvFundamentals> {
+        Arc::new(self.clone()) as Arc<dyn AvFundamentals>
+    }
+    /// Clone as `Arc<dyn AvProfile>`.
+    fn clone_arc_profile(&self) -> Arc<dyn AvProfile> {
+        Arc::new(self.clone()) as Arc<dyn AvProfile>
+    }
+    /// Clone as `Arc<dyn AvCorporateActions>`.
+    fn clone_arc_actions(&self) -> Arc<dyn AvCorporateActions> {
+        Arc::new(self.clone()) as Arc<dyn AvCorporateActions>
+    }
 }
@@ -0,0 +1,108 @@
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use serde::Deserialize;
+
+use borsa_core::{BorsaError, Quote};
+
+use super::{opt_f64, opt_string};
+use crate::session::US_EQUITY_TZ;
+
+/// Decoded `REALTIME_BULK_QUOTES` response.
+///
+/// Symbols Alpha Vantage does not know are left out of `data` rather than
+/// reported, so callers match rows back to their request by symbol. Rows are
+/// kept raw and decoded one by one with [`rows`](Self::rows), so a malformed
+/// row cannot fail the rest of the response.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BulkQuotesResponse {
+    /// One raw row per known symbol.
+    #[serde(default)]
+    pub data: Vec<serde_json::Value>,
+}
+
+impl BulkQuotesResponse {
+    /// Decode each row, keyed by the symbol it echoes (empty when it has none).
+    ///
+    /// A row that does not decode is a `BorsaError::Data` for its own symbol.
+    #[must_use]
+    pub fn rows(&self) -> Vec<(String, Result<BulkQuote, BorsaError>)> {
+        self.data
+            .iter()
+            .map(|row| {
+                let symbol = row
+                    .get("symbol")
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or_default()
+                    .to_string();
+                let quote = BulkQuote::deserialize(row).map_err(|e| {
+                    BorsaError::Data(format!("invalid bulk quote row for '{symbol}': {e}"))
+                });
+                (symbol, quote)
+            })
+            .collect()
+    }
+}
+
+/// One row of a bulk quote response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BulkQuote {
+    /// Symbol echoed back by Alpha Vantage.
+    pub symbol: String,
+    /// Time of the latest trade (`YYYY-MM-DD HH:MM:SS.fff`, US/Eastern).
+    #[serde(default, deserialize_with = "opt_string")]
+    pub timestamp: Option<String>,
+    /// Open of the current trading day.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub open: Option<f64>,
+    /// High of the current trading day.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub high: Option<f64>,
+    /// Low of the current trading day.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub low: Option<f64>,
+    /// Latest regular-session price.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub close: Option<f64>,
+    /// Volume of the current trading day.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub volume: Option<f64>,
+    /// Close of the previous trading day.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub previous_close: Option<f64>,
+    /// Price minus previous close.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub change: Option<f64>,
+    /// Change in percent, e.g. `1.3379`.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub change_percent: Option<f64>,
+    /// Latest pre- or post-market price.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub extended_hours_quote: Option<f64>,
+    /// Extended-hours price minus the regular close.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub extended_hours_change: Option<f64>,
+    /// Extended-hours change in percent.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub extended_hours_change_percent: Option<f64>,
+}
+
+impl BulkQuote {
+    /// `timestamp` as a UTC instant.
+    #[must_use]
+    pub fn time(&self) -> Option<DateTime<Utc>> {
+        let local =
+            NaiveDateTime::parse_from_str(self.timestamp.as_deref()?, "%Y-%m-%d %H:%M:%S%.f")
+                .ok()?;
+        US_EQUITY_TZ
+            .from_local_datetime(&local)
+            .single()
+            .map(|dt| dt.with_timezone(&Utc))
+    }
+
+    /// Map into borsa's USD-priced `Quote`, with the market state as of `now`.
+    ///
+    /// # Errors
+    /// Returns `BorsaError::Data` when the echoed symbol is invalid.
+    pub fn to_quote_at(&self, now: DateTime<Utc>) -> Result<Quote, BorsaError> {
+        crate::convert::map_bulk_quote(self, now)
+    }
+}
@@ -0,0 +1,74 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use borsa_core::{Action, BorsaError};
+
+use super::{opt_f64, opt_string};
+
+/// Decoded `DIVIDENDS` response.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DividendsResponse {
+    /// Symbol echoed back by Alpha Vantage.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub symbol: Option<String>,
+    /// Dividend events, most recent first.
+    #[serde(default)]
+    pub data: Vec<DividendRecord>,
+}
+
+/// One dividend event as sent by Alpha Vantage; dates are `YYYY-MM-DD`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DividendRecord {
+    /// First day the shares trade without the dividend.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub ex_dividend_date: Option<String>,
+    /// Day the board announced the dividend.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub declaration_date: Option<String>,
+    /// Day holders must be on record to receive the dividend.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub record_date: Option<String>,
+    /// Day the dividend is paid.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub payment_date: Option<String>,
+    /// Cash amount per share.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub amount: Option<f64>,
+}
+
+/// A cash dividend with its full set of dates.
+///
+/// Only the ex-dividend date is guaranteed; Alpha Vantage leaves the other
+/// dates out for older events.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dividend {
+    /// First day the shares trade without the dividend.
+    pub ex_date: NaiveDate,
+    /// Day the board announced the dividend.
+    pub declaration_date: Option<NaiveDate>,
+    /// Day holders must be on record to receive the dividend.
+    pub record_date: Option<NaiveDate>,
+    /// Day the dividend is paid.
+    pub payment_date: Option<NaiveDate>,
+    /// USD paid per share, at the precision Alpha Vantage reports it.
+    pub amount: f64,
+}
+
+impl Dividend {
+    /// Map into a borsa dividend action at the start of the ex-dividend date,
+    /// US/Eastern, where daily equity bars are stamped.
+    ///
+    /// # Errors
+    /// Returns `BorsaError::Data` when the amount cannot be expressed as money.
+    pub fn to_action(&self) -> Result<Action, BorsaError> {
+        crate::convert::dividend_action(self)
+    }
+}
+
+impl DividendsResponse {
+    /// Dividends with an ex-dividend date and a positive amount, most recent first.
+    #[must_use]
+    pub fn to_dividends(&self) -> Vec<Dividend> {
+        crate::convert::map_dividends(self)
+    }
+}
@@ -0,0 +1,82 @@
+use serde::Deserialize;
+
+use borsa_core::Earnings;
+
+use super::statements::IncomeStatementResponse;
+use super::{opt_f64, opt_string};
+
+/// Decoded `EARNINGS` response.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarningsResponse {
+    /// Symbol echoed back by Alpha Vantage; empty for unknown symbols.
+    #[serde(default)]
+    pub symbol: String,
+    /// Fiscal-year EPS rows, most recent first.
+    #[serde(default)]
+    pub annual_earnings: Vec<AnnualEarnings>,
+    /// Fiscal-quarter EPS rows, most recent first.
+    #[serde(default)]
+    pub quarterly_earnings: Vec<QuarterlyEarnings>,
+}
+
+/// One `annualEarnings` row.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnualEarnings {
+    /// Fiscal year end date (`YYYY-MM-DD`).
+    pub fiscal_date_ending: String,
+    /// Reported EPS for the year.
+    #[serde(rename = "reportedEPS", default, deserialize_with = "opt_f64")]
+    pub reported_eps: Option<f64>,
+}
+
+/// One `quarterlyEarnings` row.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuarterlyEarnings {
+    /// Fiscal quarter end date (`YYYY-MM-DD`).
+    pub fiscal_date_ending: String,
+    /// Date the results were published (`YYYY-MM-DD`).
+    #[serde(default, deserialize_with = "opt_string")]
+    pub reported_date: Option<String>,
+    /// Reported EPS.
+    #[serde(rename = "reportedEPS", default, deserialize_with = "opt_f64")]
+    pub reported_eps: Option<f64>,
+    /// Consensus EPS estimate.
+    #[serde(rename = "estimatedEPS", default, deserialize_with = "opt_f64")]
+    pub estimated_eps: Option<f64>,
+    /// Reported minus estimated EPS.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub surprise: Option<f64>,
+    /// Surprise as a percentage of the estimate.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub surprise_percentage: Option<f64>,
+    /// Session of the release, e.g. `pre-market` or `post-market`.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub report_time: Option<String>,
+}
+
+impl EarningsResponse {
+    /// True when Alpha Vantage returned the empty object it uses for unknown symbols.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.symbol.is_empty()
+            && self.annual_earnings.is_empty()
+            && self.quarterly_earnings.is_empty()
+    }
+
+    /// Map into borsa's `Earnings`; revenue and earnings stay `None`, since
+    /// `EARNINGS` only carries EPS.
+    #[must_use]
+    pub fn to_earnings(&self) -> Earnings {
+        crate::convert::map_earnings(self, None)
+    }
+
+    /// Map into borsa's `Earnings`, filling yearly and quarterly revenue and
+    /// earnings from the `income` report with the same fiscal period end.
+    #[must_use]
+    pub fn to_earnings_with(&self, income: &IncomeStatementResponse) -> Earnings {
+        crate::convert::map_earnings(self, Some(income))
+    }
+}
@@ -0,0 +1,116 @@
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::Deserialize;
+
+use borsa_core::{BorsaError, Quote};
+
+use super::{opt_f64, opt_string};
+
+/// Decoded `CURRENCY_EXCHANGE_RATE` response.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ExchangeRateResponse {
+    /// The rate; absent when Alpha Vantage does not know the pair.
+    #[serde(rename = "Realtime Currency Exchange Rate", default)]
+    pub rate: Option<ExchangeRate>,
+}
+
+/// The `Realtime Currency Exchange Rate` object, for physical and digital currencies.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExchangeRate {
+    /// Currency being priced, e.g. `EUR` or `BTC`.
+    #[serde(rename = "1. From_Currency Code")]
+    pub from_code: String,
+    /// Display name of the priced currency.
+    #[serde(
+        rename = "2. From_Currency Name",
+        default,
+        deserialize_with = "opt_string"
+    )]
+    pub from_name: Option<String>,
+    /// Currency the rate is quoted in.
+    #[serde(rename = "3. To_Currency Code")]
+    pub to_code: String,
+    /// Display name of the quote currency.
+    #[serde(
+        rename = "4. To_Currency Name",
+        default,
+        deserialize_with = "opt_string"
+    )]
+    pub to_name: Option<String>,
+    /// Units of the quote currency per unit of the priced currency.
+    #[serde(rename = "5. Exchange Rate", default, deserialize_with = "opt_f64")]
+    pub exchange_rate: Option<f64>,
+    /// Time of the rate (`YYYY-MM-DD HH:MM:SS`) in [`time_zone`](Self::time_zone).
+    #[serde(rename = "6. Last Refreshed", default, deserialize_with = "opt_string")]
+    pub last_refreshed: Option<String>,
+    /// Timezone of `last_refreshed`, usually `UTC`.
+    #[serde(rename = "7. Time Zone", default, deserialize_with = "opt_string")]
+    pub time_zone: Option<String>,
+    /// Best bid, when the source publishes one.
+    #[serde(rename = "8. Bid Price", default, deserialize_with = "opt_f64")]
+    pub bid_price: Option<f64>,
+    /// Best ask, when the source publishes one.
+    #[serde(rename = "9. Ask Price", default, deserialize_with = "opt_f64")]
+    pub ask_price: Option<f64>,
+}
+
+impl ExchangeRate {
+    /// `last_refreshed` as a UTC instant; a missing or unknown zone is read as UTC.
+    #[must_use]
+    pub fn timestamp(&self) -> Option<DateTime<Utc>> {
+        let local =
+            NaiveDateTime::parse_from_str(self.last_refreshed.as_deref()?, "%Y-%m-%d %H:%M:%S")
+                .ok()?;
+        self.time_zone
+            .as_deref()
+            .and_then(|z| z.parse::<Tz>().ok())
+            .map_or_else(
+                || Some(local.and_utc()),
+                |tz| {
+                    tz.from_local_datetime(&local)
+                        .single()
+                        .map(|dt| dt.with_timezone(&Utc))
+                },
+            )
+    }
+
+    /// The exchange rate, or the bid/ask midpoint when only the book is given.
+    #[must_use]
+    pub fn price(&self) -> Option<f64> {
+        self.exchange_rate
+            .or_else(|| match (self.bid_price, self.ask_price) {
+                (Some(bid), Some(ask)) => Some(f64::midpoint(bid, ask)),
+                _ => None,
+            })
+    }
+}
+
+impl ExchangeRateResponse {
+    /// Map into a borsa `Quote` for `symbol`, priced in the quote currency
+    /// and rounded to its minor units.
+    ///
+    /// # Errors
+    /// Returns `BorsaError::NotFound` when no rate was returned and
+    /// `BorsaError::Data` when the symbol or quote currency is invalid.
+    pub fn to_quote(&self, symbol: &str) -> Result<Quote, BorsaError> {
+        let rate = self
+            .rate
+            .as_ref()
+            .ok_or_else(|| BorsaError::not_found(format!("exchange rate for {symbol}")))?;
+        crate::convert::map_exchange_rate(rate, symbol)
+    }
+
+    /// The unrounded rate; unlike [`to_quote`](Self::to_quote), a JPY-quoted
+    /// pair keeps its decimals.
+    ///
+    /// # Errors
+    /// Returns `BorsaError::NotFound` when no rate was returned and
+    /// `BorsaError::Data` when a currency code or the rate is invalid.
+    pub fn to_exchange_rate(&self, symbol: &str) -> Result<borsa_core::ExchangeRate, BorsaError> {
+        let rate = self
+            .rate
+            .as_ref()
+            .ok_or_else(|| BorsaError::not_found(format!("exchange rate for {symbol}")))?;
+        crate::convert::exact_exchange_rate(rate)
+    }
+}
@@ -0,0 +1,309 @@
+//! Native Alpha Vantage request layer.
+//!
+//! Every endpoint the connector uses is modelled here as a typed payload and
+//! fetched with the connector's own `reqwest` client.
+
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+
+use borsa_core::BorsaError;
+
+use crate::builder::{Datatype, Entitlement};
+use crate::error::AvError;
+use crate::rate_limit::RateLimiter;
+use crate::retry::RetryPolicy;
+
+/// Payload types for the `REALTIME_BULK_QUOTES` endpoint.
+pub mod bulk_quotes;
+/// Payload types for the `DIVIDENDS` endpoint.
+pub mod dividends;
+/// Payload types for the `EARNINGS` endpoint.
+pub mod earnings;
+/// Payload types for the `CURRENCY_EXCHANGE_RATE` endpoint.
+pub mod exchange_rate;
+/// Payload types for the `OVERVIEW` endpoint.
+pub mod overview;
+/// Payload types for the `GLOBAL_QUOTE` endpoint.
+pub mod quote;
+/// Payload types for the `SYMBOL_SEARCH` endpoint.
+pub mod search;
+/// Payload types for the `SPLITS` endpoint.
+pub mod splits;
+/// Payload types for the `INCOME_STATEMENT`, `BALANCE_SHEET` and `CASH_FLOW`
+/// endpoints.
+pub mod statements;
+/// Payload types for the `TIME_SERIES_*`, `FX_*`, `DIGITAL_CURRENCY_*` and
+/// `CRYPTO_INTRADAY` endpoints.
+pub mod time_series;
+
+const BASE_URL: &str = "https://www.alphavantage.co/query";
+const RAPID_API_BASE_URL: &str = "https://alpha-vantage.p.rapidapi.com/query";
+const RAPID_API_HOST: &str = "alpha-vantage.p.rapidapi.com";
+const CONNECTOR: &str = "borsa-alphavantage";
+
+/// How requests authenticate against Alpha Vantage.
+#[derive(Clone)]
+pub(crate) enum Auth {
+    /// Native Alpha Vantage key, sent as the `apikey` query parameter.
+    Key(String),
+    /// `RapidAPI` key, sent as request headers.
+    RapidApi(String),
+}
+
+/// Minimal HTTP client that issues `query?function=...` requests.
+#[derive(Clone)]
+pub(crate) struct Client {
+    http: reqwest::Client,
+    auth: Auth,
+    base_url: String,
+    timeout: Option<Duration>,
+    entitlement: Option<Entitlement>,
+    datatype: Datatype,
+    limiter: RateLimiter,
+    retry: RetryPolicy,
+}
+
+impl Client {
+    pub(crate) fn new(http: reqwest::Client, auth: Auth) -> Self {
+        let base_url = match auth {
+            Auth::Key(_) => BASE_URL,
+            Auth::RapidApi(_) => RAPID_API_BASE_URL,
+        }
+        .to_string();
+        Self {
+            http,
+            auth,
+            base_url,
+            timeout: None,
+            entitlement: None,
+            datatype: Datatype::Json,
+            limiter: RateLimiter::default(),
+            retry: RetryPolicy::default(),
+        }
+    }
+
+    /// Send every request to `url` instead of the Alpha Vantage host.
+    pub(crate) fn set_base_url(&mut self, url: String) {
+        self.base_url = url;
+    }
+
+    pub(crate) const fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = Some(timeout);
+    }
+
+    pub(crate) const fn set_entitlement(&mut self, entitlement: Entitlement) {
+        self.entitlement = Some(entitlement);
+    }
+
+    pub(crate) const fn set_datatype(&mut self, datatype: Datatype) {
+        self.datatype = datatype;
+    }
+
+    /// Response format requested for history series.
+    pub(crate) const fn datatype(&self) -> Datatype {
+        self.datatype
+    }
+
+    /// Apply the per-request timeout and, where `function` accepts it, the entitlement.
+    fn decorate(
+        &self,
+        mut req: reqwest::RequestBuilder,
+        function: &str,
+    ) -> reqwest::RequestBuilder {
+        if let Some(timeout) = self.timeout {
+            req = req.timeout(timeout);
+        }
+        if let Some(entitlement) = self.entitlement
+            && accepts_entitlement(function)
+        {
+            req = req.query(&[("entitlement", entitlement.as_str())]);
+        }
+        req
+    }
+
+    /// Replace the request scheduler.
+    pub(crate) fn set_rate_limiter(&mut self, limiter: RateLimiter) {
+        self.limiter = limiter;
+    }
+
+    /// Replace the retry policy.
+    pub(crate) const fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry = policy;
+    }
+
+    /// Run `op` once per attempt, taking a rate-limit token before each and
+    /// retrying transient failures according to the retry policy.
+    pub(crate) async fn retrying<T, F, Fut>(&self, mut op: F) -> Result<T, BorsaError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Failure>>,
+    {
+        let mut failed = 0;
+        loop {
+            self.limiter.acquire().await?;
+            let (error, hint) = match op().await {
+                Ok(v) => return Ok(v),
+                Err(Failure::Permanent(e)) => return Err(e),
+                Err(Failure::Transient(e)) => (e, None),
+                Err(Failure::RateLimited(e, hint)) => (e, hint),
+            };
+            failed += 1;
+            match self.retry.next_delay(failed, hint) {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return Err(error),
+            }
+        }
+    }
+
+    /// Call `function` with extra query parameters and decode the JSON body into `T`.
+    pub(crate) async fn get_json<T: DeserializeOwned>(
+        &self,
+        function: &str,
+        params: &[(&str, &str)],
+    ) -> Result<T, BorsaError> {
+        self.retrying(|| async move {
+            let body = self.get_once(function, params).await?;
+            decode(&body).map_err(Failure::from)
+        })
+        .await
+    }
+
+    /// Call `function` with `datatype=csv` and return the CSV body.
+    ///
+    /// Errors still arrive as JSON envelopes and are surfaced like [`Client::get_json`]'s.
+    pub(crate) async fn get_csv(
+        &self,
+        function: &str,
+        params: &[(&str, &str)],
+    ) -> Result<String, BorsaError> {
+        let params = [params, &[("datatype", Datatype::Csv.as_str())]].concat();
+        self.retrying(|| async {
+            let body = self.get_once(function, &params).await?;
+            if body.trim_start().starts_with('{') {
+                decode::<serde_json::Value>(&body)?;
+                return Err(Failure::Permanent(BorsaError::Data(format!(
+                    "expected CSV from {function}, got JSON"
+                ))));
+            }
+            Ok(body)
+        })
+        .await
+    }
+
+    /// One attempt at `function`, returning the raw body of a successful response.
+    async fn get_once(&self, function: &str, params: &[(&str, &str)]) -> Result<String, Failure> {
+        let req = match &self.auth {
+            Auth::Key(key) => self
+                .http
+                .get(&self.base_url)
+                .query(&[("function", function)])
+                .query(params)
+                .query(&[("apikey", key.as_str())]),
+            Auth::RapidApi(key) => self
+                .http
+                .get(&self.base_url)
+                .query(&[("function", function)])
+                .query(params)
+                .header("x-rapidapi-host", RAPID_API_HOST)
+                .header("x-rapidapi-key", key),
+        };
+        let req = self.decorate(req, function);
+        let transport = |e: reqwest::Error| {
+            if e.is_timeout() {
+                Failure::Transient(BorsaError::provider_timeout(CONNECTOR, function))
+            } else {
+                Failure::Permanent(
+                    AvError::Transport {
+                        message: e.to_string(),
+                    }
+                    .into(),
+                )
+            }
+        };
+        let resp = req.send().await.map_err(transport)?;
+        let status = resp.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let hint = resp
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+                .map(Duration::from_secs);
+            let err = AvError::RateLimited {
+                retry_after: hint,
+                message: format!("HTTP {status}"),
+            };
+            return Err(Failure::RateLimited(err.into(), hint));
+        }
+        if !status.is_success() {
+            let err = AvError::HttpStatus {
+                status: status.as_u16(),
+            }
+            .into();
+            return Err(if status.is_server_error() {
+                Failure::Transient(err)
+            } else {
+                Failure::Permanent(err)
+            });
+        }
+        resp.text().await.map_err(transport)
+    }
+}
+
+/// Whether `function` serves US market data gated by the `entitlement` parameter.
+fn accepts_entitlement(function: &str) -> bool {
+    function.starts_with("TIME_SERIES_")
+        || matches!(function, "GLOBAL_QUOTE" | "REALTIME_BULK_QUOTES")
+}
+
+/// Outcome of a failed attempt, deciding whether it is retried.
+pub(crate) enum Failure {
+    /// Surfaced immediately.
+    Permanent(BorsaError),
+    /// Timeouts and server errors, retried with backoff.
+    Transient(BorsaError),
+    /// Throttled within the minute, retried after the hint (capped by the policy)
+    /// when one is given. Spent daily budgets are permanent.
+    RateLimited(BorsaError, Option<Duration>),
+}
+
+impl From<BorsaError> for Failure {
+    fn from(e: BorsaError) -> Self {
+        match AvError::from_borsa(&e) {
+            Some(limited) if limited.is_daily_limit() => Self::Permanent(e),
+            Some(AvError::RateLimited { retry_after, .. }) => Self::RateLimited(e, retry_after),
+            _ if matches!(e, BorsaError::ProviderTimeout { .. }) => Self::Transient(e),
+            _ => Self::Permanent(e),
+        }
+    }
+}
+
+/// Decode a response body, surfacing Alpha Vantage's envelopes as typed [`AvError`]s.
+pub(crate) fn decode<T: DeserializeOwned>(body: &str) -> Result<T, BorsaError> {
+    let value: serde_json::Value =
+        serde_json::from_str(body).map_err(|e| BorsaError::connector(CONNECTOR, e.to_string()))?;
+    for key in ["Error Message", "Information", "Note"] {
+        if let Some(msg) = value.get(key).and_then(serde_json::Value::as_str) {
+            return Err(AvError::from_envelope(key, msg).into());
+        }
+    }
+    serde_json::from_value(value).map_err(|e| BorsaError::Data(e.to_string()))
+}
+
+/// Alpha Vantage encodes numbers as strings and uses `"None"` or `"-"` for missing values.
+fn opt_f64<'de, D: Deserializer<'de>>(d: D) -> Result<Option<f64>, D::Error> {
+    let s = Option::<String>::deserialize(d)?;
+    Ok(s.and_then(|s| s.trim().parse::<f64>().ok())
+        .filter(|v| v.is_finite()))
+}
+
+/// Like [`opt_f64`] for string fields: `"None"` and empty strings become `None`.
+fn opt_string<'de, D: Deserializer<'de>>(d: D) -> Result<Option<String>, D::Error> {
+    let s = Option::<String>::deserialize(d)?;
+    Ok(s.filter(|s| {
+        let t = s.trim();
+        !t.is_empty() && t != "None" && t != "-"
+    }))
+}
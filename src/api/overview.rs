@@ -0,0 +1,76 @@
+use serde::Deserialize;
+
+use borsa_core::{BorsaError, Profile};
+
+use super::opt_string;
+
+/// Decoded `OVERVIEW` response.
+///
+/// Only the descriptive fields are modelled; the valuation ratios Alpha
+/// Vantage sends alongside them are ignored.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct OverviewResponse {
+    /// Symbol echoed back by Alpha Vantage; absent for unknown symbols.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub symbol: Option<String>,
+    /// Asset type, e.g. `Common Stock` or `ETF`.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub asset_type: Option<String>,
+    /// Company or fund name.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub name: Option<String>,
+    /// Business description.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub description: Option<String>,
+    /// SEC Central Index Key.
+    #[serde(rename = "CIK", default, deserialize_with = "opt_string")]
+    pub cik: Option<String>,
+    /// Listing exchange, e.g. `NYSE`.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub exchange: Option<String>,
+    /// Reporting currency code.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub currency: Option<String>,
+    /// Country of incorporation, e.g. `USA`.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub country: Option<String>,
+    /// Sector, e.g. `TECHNOLOGY`.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub sector: Option<String>,
+    /// Industry, e.g. `COMPUTER & OFFICE EQUIPMENT`.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub industry: Option<String>,
+    /// Registered address on one line, e.g. `1 NEW ORCHARD ROAD, ARMONK, NY, US`.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub address: Option<String>,
+    /// Company website.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub official_site: Option<String>,
+    /// Month the fiscal year ends, e.g. `December`.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub fiscal_year_end: Option<String>,
+    /// End of the latest reported quarter (`YYYY-MM-DD`).
+    #[serde(default, deserialize_with = "opt_string")]
+    pub latest_quarter: Option<String>,
+}
+
+impl OverviewResponse {
+    /// True when Alpha Vantage returned the empty object it uses for unknown symbols.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.symbol.is_none() && self.name.is_none()
+    }
+
+    /// Map into a borsa company profile, or a fund profile for ETFs and
+    /// mutual funds.
+    ///
+    /// Exchange, currency, fiscal year end and CIK have no place in borsa's
+    /// profile types and are read from this payload directly.
+    ///
+    /// # Errors
+    /// Returns `BorsaError::Data` when the response carries no name.
+    pub fn to_profile(&self) -> Result<Profile, BorsaError> {
+        crate::convert::map_overview(self)
+    }
+}
@@ -0,0 +1,120 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+
+use borsa_core::{BorsaError, Quote};
+
+use super::{opt_f64, opt_string};
+
+/// Decoded `GLOBAL_QUOTE` response.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GlobalQuoteResponse {
+    /// The quote; Alpha Vantage sends an empty object for unknown symbols.
+    #[serde(rename = "Global Quote", default)]
+    pub quote: GlobalQuote,
+}
+
+/// The `Global Quote` object.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GlobalQuote {
+    /// Symbol echoed back by Alpha Vantage.
+    #[serde(rename = "01. symbol", default, deserialize_with = "opt_string")]
+    pub symbol: Option<String>,
+    /// Open of the latest trading day.
+    #[serde(rename = "02. open", default, deserialize_with = "opt_f64")]
+    pub open: Option<f64>,
+    /// High of the latest trading day.
+    #[serde(rename = "03. high", default, deserialize_with = "opt_f64")]
+    pub high: Option<f64>,
+    /// Low of the latest trading day.
+    #[serde(rename = "04. low", default, deserialize_with = "opt_f64")]
+    pub low: Option<f64>,
+    /// Latest price.
+    #[serde(rename = "05. price", default, deserialize_with = "opt_f64")]
+    pub price: Option<f64>,
+    /// Volume of the latest trading day.
+    #[serde(rename = "06. volume", default, deserialize_with = "opt_f64")]
+    pub volume: Option<f64>,
+    /// Latest trading day (`YYYY-MM-DD`).
+    #[serde(
+        rename = "07. latest trading day",
+        default,
+        deserialize_with = "opt_string"
+    )]
+    pub latest_trading_day: Option<String>,
+    /// Close of the previous trading day.
+    #[serde(rename = "08. previous close", default, deserialize_with = "opt_f64")]
+    pub previous_close: Option<f64>,
+    /// Price minus previous close.
+    #[serde(rename = "09. change", default, deserialize_with = "opt_f64")]
+    pub change: Option<f64>,
+    /// Change as a percentage string, e.g. `1.3379%`.
+    #[serde(
+        rename = "10. change percent",
+        default,
+        deserialize_with = "opt_string"
+    )]
+    pub change_percent: Option<String>,
+}
+
+impl GlobalQuote {
+    /// Latest trading day as a date.
+    #[must_use]
+    pub fn trading_day(&self) -> Option<NaiveDate> {
+        self.latest_trading_day
+            .as_deref()
+            .and_then(|d| NaiveDate::parse_from_str(d.trim(), "%Y-%m-%d").ok())
+    }
+
+    /// Whether the quote lags the exchange at `now`; see
+    /// [`us_equity_quote_is_stale`](crate::session::us_equity_quote_is_stale).
+    #[must_use]
+    pub fn is_stale_at(&self, now: DateTime<Utc>) -> bool {
+        self.trading_day()
+            .is_some_and(|day| crate::session::us_equity_quote_is_stale(day, now))
+    }
+
+    /// Change percent as a number, e.g. `1.3379` for `1.3379%`.
+    #[must_use]
+    pub fn change_percent_value(&self) -> Option<f64> {
+        self.change_percent
+            .as_deref()
+            .and_then(|p| p.trim().trim_end_matches('%').parse().ok())
+    }
+
+    /// Low and high of the latest trading day.
+    #[must_use]
+    pub const fn day_range(&self) -> Option<(f64, f64)> {
+        match (self.low, self.high) {
+            (Some(low), Some(high)) => Some((low, high)),
+            _ => None,
+        }
+    }
+}
+
+impl GlobalQuoteResponse {
+    /// True when Alpha Vantage returned the empty quote it uses for unknown symbols.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.quote.symbol.is_none()
+    }
+
+    /// Map into borsa's USD-priced `Quote`, with the market state as of now.
+    ///
+    /// `GLOBAL_QUOTE` carries no name or exchange, so `shortname` and
+    /// `exchange` stay `None`; day range, volume and change are read from
+    /// [`GlobalQuote`] directly.
+    ///
+    /// # Errors
+    /// Returns `BorsaError::Data` when the echoed symbol is invalid.
+    pub fn to_quote(&self) -> Result<Quote, BorsaError> {
+        self.to_quote_at(Utc::now())
+    }
+
+    /// Like [`to_quote`](Self::to_quote), with the market state as of `now`.
+    ///
+    /// # Errors
+    /// Returns `BorsaError::Data` when the echoed symbol is invalid.
+    pub fn to_quote_at(&self, now: DateTime<Utc>) -> Result<Quote, BorsaError> {
+        crate::convert::map_global_quote(&self.quote, now)
+    }
+}
@@ -0,0 +1,43 @@
+use serde::Deserialize;
+
+use super::{opt_f64, opt_string};
+
+/// Decoded `SYMBOL_SEARCH` response.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SearchResponse {
+    /// Matches ordered by Alpha Vantage's match score.
+    #[serde(rename = "bestMatches", default)]
+    pub best_matches: Vec<SearchMatch>,
+}
+
+/// One `bestMatches` entry.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SearchMatch {
+    /// Ticker symbol, with an exchange suffix outside the US (e.g. `TSCO.LON`).
+    #[serde(rename = "1. symbol")]
+    pub symbol: String,
+    /// Security name.
+    #[serde(rename = "2. name", default)]
+    pub name: String,
+    /// Security type, e.g. `Equity`, `ETF` or `Mutual Fund`.
+    #[serde(rename = "3. type", default)]
+    pub kind: String,
+    /// Listing region, e.g. `United States`.
+    #[serde(rename = "4. region", default)]
+    pub region: String,
+    /// Local market open (`HH:MM`).
+    #[serde(rename = "5. marketOpen", default, deserialize_with = "opt_string")]
+    pub market_open: Option<String>,
+    /// Local market close (`HH:MM`).
+    #[serde(rename = "6. marketClose", default, deserialize_with = "opt_string")]
+    pub market_close: Option<String>,
+    /// Market timezone as a UTC offset, e.g. `UTC-04`.
+    #[serde(rename = "7. timezone", default, deserialize_with = "opt_string")]
+    pub timezone: Option<String>,
+    /// Trading currency code.
+    #[serde(rename = "8. currency", default, deserialize_with = "opt_string")]
+    pub currency: Option<String>,
+    /// Relevance between 0 and 1.
+    #[serde(rename = "9. matchScore", default, deserialize_with = "opt_f64")]
+    pub match_score: Option<f64>,
+}
@@ -0,0 +1,41 @@
+use serde::Deserialize;
+
+use borsa_core::{Action, BorsaError};
+
+use super::{opt_f64, opt_string};
+
+/// Decoded `SPLITS` response.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SplitsResponse {
+    /// Symbol echoed back by Alpha Vantage.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub symbol: Option<String>,
+    /// Split events, most recent first.
+    #[serde(default)]
+    pub data: Vec<SplitRecord>,
+}
+
+/// One split event as sent by Alpha Vantage.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SplitRecord {
+    /// First trading day on the new share basis (`YYYY-MM-DD`).
+    #[serde(default, deserialize_with = "opt_string")]
+    pub effective_date: Option<String>,
+    /// Shares after the split per share before, e.g. `1.5` for 3:2.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub split_factor: Option<f64>,
+}
+
+impl SplitsResponse {
+    /// Map into `Action::Split` entries at midnight US/Eastern on the
+    /// effective date, most recent first.
+    ///
+    /// Events without a date, or whose factor is not a ratio of small
+    /// integers, are left out.
+    ///
+    /// # Errors
+    /// Returns `BorsaError::Data` when an effective date has no US/Eastern midnight.
+    pub fn to_actions(&self) -> Result<Vec<Action>, BorsaError> {
+        crate::convert::map_splits(self)
+    }
+}
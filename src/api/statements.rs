@@ -0,0 +1,395 @@
+use serde::{Deserialize, Deserializer};
+
+use borsa_core::{BalanceSheetRow, BorsaError, CashflowRow, IncomeStatementRow};
+
+use super::{opt_f64, opt_string};
+
+/// Decoded `INCOME_STATEMENT`, `BALANCE_SHEET` or `CASH_FLOW` response.
+///
+/// All three share one shape: the symbol and per-period reports, most recent
+/// first, whose line items depend on the statement.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", bound = "R: Deserialize<'de>")]
+pub struct StatementResponse<R> {
+    /// Symbol echoed back by Alpha Vantage; empty for unknown symbols.
+    #[serde(default)]
+    pub symbol: String,
+    /// Fiscal-year reports.
+    #[serde(default)]
+    pub annual_reports: Vec<R>,
+    /// Fiscal-quarter reports.
+    #[serde(default)]
+    pub quarterly_reports: Vec<R>,
+}
+
+/// Decoded `INCOME_STATEMENT` response.
+pub type IncomeStatementResponse = StatementResponse<IncomeStatementReport>;
+/// Decoded `BALANCE_SHEET` response.
+pub type BalanceSheetResponse = StatementResponse<BalanceSheetReport>;
+/// Decoded `CASH_FLOW` response.
+pub type CashFlowResponse = StatementResponse<CashFlowReport>;
+
+impl<R> StatementResponse<R> {
+    /// True when Alpha Vantage returned the empty object it uses for unknown symbols.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.symbol.is_empty()
+            && self.annual_reports.is_empty()
+            && self.quarterly_reports.is_empty()
+    }
+
+    /// Quarterly or annual reports.
+    #[must_use]
+    pub fn reports(&self, quarterly: bool) -> &[R] {
+        if quarterly {
+            &self.quarterly_reports
+        } else {
+            &self.annual_reports
+        }
+    }
+}
+
+fn fiscal_date<'de, D: Deserializer<'de>>(d: D) -> Result<String, D::Error> {
+    Ok(String::deserialize(d)?.trim().to_string())
+}
+
+/// One `INCOME_STATEMENT` report.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomeStatementReport {
+    /// Fiscal period end date (`YYYY-MM-DD`).
+    #[serde(deserialize_with = "fiscal_date")]
+    pub fiscal_date_ending: String,
+    /// Currency the line items are reported in.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub reported_currency: Option<String>,
+    /// Gross profit.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub gross_profit: Option<f64>,
+    /// Total revenue.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub total_revenue: Option<f64>,
+    /// Cost of revenue.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub cost_of_revenue: Option<f64>,
+    /// Cost of goods and services sold.
+    #[serde(
+        rename = "costofGoodsAndServicesSold",
+        default,
+        deserialize_with = "opt_f64"
+    )]
+    pub cost_of_goods_and_services_sold: Option<f64>,
+    /// Operating income.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub operating_income: Option<f64>,
+    /// Selling, general and administrative expense.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub selling_general_and_administrative: Option<f64>,
+    /// Research and development expense.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub research_and_development: Option<f64>,
+    /// Total operating expenses.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub operating_expenses: Option<f64>,
+    /// Net investment income.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub investment_income_net: Option<f64>,
+    /// Net interest income.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub net_interest_income: Option<f64>,
+    /// Interest income.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub interest_income: Option<f64>,
+    /// Interest expense.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub interest_expense: Option<f64>,
+    /// Non-interest income.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub non_interest_income: Option<f64>,
+    /// Other non-operating income.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub other_non_operating_income: Option<f64>,
+    /// Depreciation.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub depreciation: Option<f64>,
+    /// Depreciation and amortization.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub depreciation_and_amortization: Option<f64>,
+    /// Income before tax.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub income_before_tax: Option<f64>,
+    /// Income tax expense.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub income_tax_expense: Option<f64>,
+    /// Interest and debt expense.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub interest_and_debt_expense: Option<f64>,
+    /// Net income from continuing operations.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub net_income_from_continuing_operations: Option<f64>,
+    /// Comprehensive income, net of tax.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub comprehensive_income_net_of_tax: Option<f64>,
+    /// Earnings before interest and taxes.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub ebit: Option<f64>,
+    /// Earnings before interest, taxes, depreciation and amortization.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub ebitda: Option<f64>,
+    /// Net income.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub net_income: Option<f64>,
+}
+
+impl IncomeStatementReport {
+    /// Map into a borsa `IncomeStatementRow`.
+    ///
+    /// # Errors
+    /// Returns `BorsaError::Data` when the fiscal date or currency is invalid.
+    pub fn to_row(&self) -> Result<IncomeStatementRow, BorsaError> {
+        crate::convert::map_income_statement(self)
+    }
+}
+
+/// One `BALANCE_SHEET` report.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceSheetReport {
+    /// Fiscal period end date (`YYYY-MM-DD`).
+    #[serde(deserialize_with = "fiscal_date")]
+    pub fiscal_date_ending: String,
+    /// Currency the line items are reported in.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub reported_currency: Option<String>,
+    /// Total assets.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub total_assets: Option<f64>,
+    /// Total current assets.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub total_current_assets: Option<f64>,
+    /// Cash and cash equivalents at carrying value.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub cash_and_cash_equivalents_at_carrying_value: Option<f64>,
+    /// Cash and short-term investments.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub cash_and_short_term_investments: Option<f64>,
+    /// Inventory.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub inventory: Option<f64>,
+    /// Current net receivables.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub current_net_receivables: Option<f64>,
+    /// Total non-current assets.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub total_non_current_assets: Option<f64>,
+    /// Property, plant and equipment.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub property_plant_equipment: Option<f64>,
+    /// Accumulated depreciation and amortization of property, plant and equipment.
+    #[serde(
+        rename = "accumulatedDepreciationAmortizationPPE",
+        default,
+        deserialize_with = "opt_f64"
+    )]
+    pub accumulated_depreciation_amortization_ppe: Option<f64>,
+    /// Intangible assets.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub intangible_assets: Option<f64>,
+    /// Intangible assets excluding goodwill.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub intangible_assets_excluding_goodwill: Option<f64>,
+    /// Goodwill.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub goodwill: Option<f64>,
+    /// Investments.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub investments: Option<f64>,
+    /// Long-term investments.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub long_term_investments: Option<f64>,
+    /// Short-term investments.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub short_term_investments: Option<f64>,
+    /// Other current assets.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub other_current_assets: Option<f64>,
+    /// Other non-current assets.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub other_non_current_assets: Option<f64>,
+    /// Total liabilities.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub total_liabilities: Option<f64>,
+    /// Total current liabilities.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub total_current_liabilities: Option<f64>,
+    /// Current accounts payable.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub current_accounts_payable: Option<f64>,
+    /// Deferred revenue.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub deferred_revenue: Option<f64>,
+    /// Current debt.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub current_debt: Option<f64>,
+    /// Short-term debt.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub short_term_debt: Option<f64>,
+    /// Total non-current liabilities.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub total_non_current_liabilities: Option<f64>,
+    /// Capital lease obligations.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub capital_lease_obligations: Option<f64>,
+    /// Long-term debt.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub long_term_debt: Option<f64>,
+    /// Current portion of long-term debt.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub current_long_term_debt: Option<f64>,
+    /// Non-current long-term debt.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub long_term_debt_noncurrent: Option<f64>,
+    /// Short- and long-term debt combined.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub short_long_term_debt_total: Option<f64>,
+    /// Other current liabilities.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub other_current_liabilities: Option<f64>,
+    /// Other non-current liabilities.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub other_non_current_liabilities: Option<f64>,
+    /// Total shareholder equity.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub total_shareholder_equity: Option<f64>,
+    /// Treasury stock.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub treasury_stock: Option<f64>,
+    /// Retained earnings.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub retained_earnings: Option<f64>,
+    /// Common stock.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub common_stock: Option<f64>,
+    /// Common shares outstanding.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub common_stock_shares_outstanding: Option<f64>,
+}
+
+impl BalanceSheetReport {
+    /// Map into a borsa `BalanceSheetRow`.
+    ///
+    /// # Errors
+    /// Returns `BorsaError::Data` when the fiscal date or currency is invalid.
+    pub fn to_row(&self) -> Result<BalanceSheetRow, BorsaError> {
+        crate::convert::map_balance_sheet(self)
+    }
+}
+
+/// One `CASH_FLOW` report.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CashFlowReport {
+    /// Fiscal period end date (`YYYY-MM-DD`).
+    #[serde(deserialize_with = "fiscal_date")]
+    pub fiscal_date_ending: String,
+    /// Currency the line items are reported in.
+    #[serde(default, deserialize_with = "opt_string")]
+    pub reported_currency: Option<String>,
+    /// Cash flow from operating activities.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub operating_cashflow: Option<f64>,
+    /// Payments for operating activities.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub payments_for_operating_activities: Option<f64>,
+    /// Proceeds from operating activities.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub proceeds_from_operating_activities: Option<f64>,
+    /// Change in operating liabilities.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub change_in_operating_liabilities: Option<f64>,
+    /// Change in operating assets.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub change_in_operating_assets: Option<f64>,
+    /// Depreciation, depletion and amortization.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub depreciation_depletion_and_amortization: Option<f64>,
+    /// Capital expenditures, reported as a positive outflow.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub capital_expenditures: Option<f64>,
+    /// Change in receivables.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub change_in_receivables: Option<f64>,
+    /// Change in inventory.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub change_in_inventory: Option<f64>,
+    /// Profit or loss.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub profit_loss: Option<f64>,
+    /// Cash flow from investing activities.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub cashflow_from_investment: Option<f64>,
+    /// Cash flow from financing activities.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub cashflow_from_financing: Option<f64>,
+    /// Net proceeds from repayments of short-term debt.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub proceeds_from_repayments_of_short_term_debt: Option<f64>,
+    /// Payments for repurchase of common stock.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub payments_for_repurchase_of_common_stock: Option<f64>,
+    /// Payments for repurchase of equity.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub payments_for_repurchase_of_equity: Option<f64>,
+    /// Payments for repurchase of preferred stock.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub payments_for_repurchase_of_preferred_stock: Option<f64>,
+    /// Total dividends paid.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub dividend_payout: Option<f64>,
+    /// Dividends paid on common stock.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub dividend_payout_common_stock: Option<f64>,
+    /// Dividends paid on preferred stock.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub dividend_payout_preferred_stock: Option<f64>,
+    /// Proceeds from issuance of common stock.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub proceeds_from_issuance_of_common_stock: Option<f64>,
+    /// Net proceeds from issuance of long-term debt and capital securities.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub proceeds_from_issuance_of_long_term_debt_and_capital_securities_net: Option<f64>,
+    /// Proceeds from issuance of preferred stock.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub proceeds_from_issuance_of_preferred_stock: Option<f64>,
+    /// Proceeds from repurchase of equity.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub proceeds_from_repurchase_of_equity: Option<f64>,
+    /// Proceeds from sale of treasury stock.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub proceeds_from_sale_of_treasury_stock: Option<f64>,
+    /// Change in cash and cash equivalents.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub change_in_cash_and_cash_equivalents: Option<f64>,
+    /// Effect of exchange rate changes on cash.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub change_in_exchange_rate: Option<f64>,
+    /// Net income.
+    #[serde(default, deserialize_with = "opt_f64")]
+    pub net_income: Option<f64>,
+}
+
+impl CashFlowReport {
+    /// Free cash flow: operating cash flow less capital expenditures.
+    #[must_use]
+    pub fn free_cash_flow(&self) -> Option<f64> {
+        Some(self.operating_cashflow? - self.capital_expenditures?)
+    }
+
+    /// Map into a borsa `CashflowRow`.
+    ///
+    /// # Errors
+    /// Returns `BorsaError::Data` when the fiscal date or currency is invalid.
+    pub fn to_row(&self) -> Result<CashflowRow, BorsaError> {
+        crate::convert::map_cash_flow(self)
+    }
+}
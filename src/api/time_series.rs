@@ -0,0 +1,124 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer};
+
+use borsa_core::{BorsaError, Currency, HistoryResponse};
+
+use super::opt_f64;
+use crate::window::HistoryWindow;
+
+/// Decoded `TIME_SERIES_*` JSON response.
+///
+/// Alpha Vantage names the series object after the function (e.g.
+/// `"Time Series (5min)"`), so it is picked up by prefix.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(from = "RawTimeSeries")]
+pub struct TimeSeriesResponse {
+    /// `Meta Data` entries keyed by their numbered labels.
+    pub meta_data: BTreeMap<String, String>,
+    /// Bars keyed by their timestamp string.
+    pub bars: BTreeMap<String, Bar>,
+}
+
+#[derive(Deserialize)]
+struct RawTimeSeries {
+    #[serde(rename = "Meta Data", default)]
+    meta_data: BTreeMap<String, String>,
+    #[serde(flatten)]
+    series: BTreeMap<String, BTreeMap<String, Bar>>,
+}
+
+impl From<RawTimeSeries> for TimeSeriesResponse {
+    fn from(raw: RawTimeSeries) -> Self {
+        let bars = raw
+            .series
+            .into_iter()
+            .find(|(k, _)| k.contains("Time Series"))
+            .map(|(_, v)| v)
+            .unwrap_or_default();
+        Self {
+            meta_data: raw.meta_data,
+            bars,
+        }
+    }
+}
+
+/// One OHLCV bar.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Bar {
+    /// Open price.
+    #[serde(rename = "1. open", deserialize_with = "f64_str")]
+    pub open: f64,
+    /// High price.
+    #[serde(rename = "2. high", deserialize_with = "f64_str")]
+    pub high: f64,
+    /// Low price.
+    #[serde(rename = "3. low", deserialize_with = "f64_str")]
+    pub low: f64,
+    /// Close price.
+    #[serde(rename = "4. close", deserialize_with = "f64_str")]
+    pub close: f64,
+    /// Adjusted close, present on `*_ADJUSTED` daily, weekly and monthly series.
+    #[serde(rename = "5. adjusted close", default, deserialize_with = "opt_f64")]
+    pub adjusted_close: Option<f64>,
+    /// Traded volume.
+    #[serde(
+        rename = "5. volume",
+        alias = "6. volume",
+        default,
+        deserialize_with = "opt_f64"
+    )]
+    pub volume: Option<f64>,
+    /// Cash dividend paid on this bar, on adjusted series.
+    #[serde(rename = "7. dividend amount", default, deserialize_with = "opt_f64")]
+    pub dividend_amount: Option<f64>,
+    /// Split coefficient for this bar, on the daily adjusted series.
+    #[serde(rename = "8. split coefficient", default, deserialize_with = "opt_f64")]
+    pub split_coefficient: Option<f64>,
+}
+
+fn f64_str<'de, D: Deserializer<'de>>(d: D) -> Result<f64, D::Error> {
+    let s = String::deserialize(d)?;
+    s.trim().parse::<f64>().map_err(serde::de::Error::custom)
+}
+
+impl TimeSeriesResponse {
+    /// IANA timezone the bar timestamps are expressed in.
+    #[must_use]
+    pub fn time_zone(&self) -> Option<&str> {
+        self.meta_data
+            .iter()
+            .find(|(k, _)| k.ends_with("Time Zone"))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Map into a borsa `HistoryResponse` priced in `currency` and trimmed to `window`.
+    ///
+    /// # Errors
+    /// Returns `BorsaError::Data` when `currency` has no registered precision.
+    pub fn to_history(
+        &self,
+        currency: &Currency,
+        window: &HistoryWindow,
+    ) -> Result<HistoryResponse, BorsaError> {
+        crate::convert::map_series_to_history(self, currency, window)
+    }
+}
+
+/// Map a `datatype=csv` time series body into a borsa `HistoryResponse`.
+///
+/// CSV bodies carry no `Meta Data`, so the caller names the timezone the
+/// timestamps are in (`US/Eastern` for US equities, `UTC` for forex and
+/// crypto). Rows are parsed one at a time straight into candles.
+///
+/// # Errors
+/// Returns `BorsaError::Data` when the header lacks a price column, a row is
+/// malformed, or `currency` has no registered precision.
+pub fn csv_to_history(
+    body: &str,
+    time_zone: &str,
+    currency: &Currency,
+    window: &HistoryWindow,
+) -> Result<HistoryResponse, BorsaError> {
+    crate::convert::map_csv_to_history(body, time_zone, currency, window)
+}
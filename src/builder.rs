@@ -0,0 +1,309 @@
+use std::time::Duration;
+
+use borsa_core::BorsaError;
+
+use crate::AvConnector;
+use crate::adapter::RealAdapter;
+use crate::api::Auth;
+use crate::rate_limit::{RateLimitTier, RateLimiter, WhenExhausted};
+use crate::retry::RetryPolicy;
+
+/// How the internally built HTTP client handles proxies.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ProxyMode {
+    /// Honour the `HTTP_PROXY`/`HTTPS_PROXY`/`NO_PROXY` environment.
+    #[default]
+    System,
+    /// Connect directly, ignoring any system proxy.
+    Disabled,
+    /// Route every request through the given proxy URL.
+    Url(String),
+}
+
+/// US market data entitlement requested from Alpha Vantage.
+///
+/// Only sent on endpoints that accept it (`TIME_SERIES_*`, `GLOBAL_QUOTE`,
+/// `REALTIME_BULK_QUOTES`); without it Alpha Vantage serves end-of-day data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Entitlement {
+    /// Realtime data; requires a premium plan with realtime access.
+    Realtime,
+    /// 15-minute delayed data.
+    Delayed,
+}
+
+impl Entitlement {
+    /// Value of the `entitlement` query parameter.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Realtime => "realtime",
+            Self::Delayed => "delayed",
+        }
+    }
+}
+
+/// Response format requested for history series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Datatype {
+    /// Nested JSON objects, with the series timezone in `Meta Data`.
+    #[default]
+    Json,
+    /// Comma-separated rows: a much smaller body that parses faster on full
+    /// daily histories and month-sliced intraday downloads.
+    Csv,
+}
+
+impl Datatype {
+    /// Value of the `datatype` query parameter.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+/// Builder for [`AvConnector`], obtained from [`AvConnector::builder`].
+#[derive(Clone, Default)]
+pub struct AvConnectorBuilder {
+    auth: Option<Auth>,
+    http: Option<reqwest::Client>,
+    base_url: Option<String>,
+    timeout: Option<Duration>,
+    proxy: Option<ProxyMode>,
+    user_agent: Option<String>,
+    tier: RateLimitTier,
+    when_exhausted: WhenExhausted,
+    entitlement: Option<Entitlement>,
+    datatype: Datatype,
+    crypto_market: Option<String>,
+    retry: RetryPolicy,
+    earnings_income_statement: bool,
+    history_actions: bool,
+    max_intraday_months: Option<usize>,
+}
+
+impl AvConnectorBuilder {
+    /// Authenticate with a native Alpha Vantage API key.
+    #[must_use]
+    pub fn api_key(mut self, key: impl Into<String>) -> Self {
+        self.auth = Some(Auth::Key(key.into()));
+        self
+    }
+
+    /// Authenticate with a `RapidAPI` key.
+    #[must_use]
+    pub fn rapidapi_key(mut self, key: impl Into<String>) -> Self {
+        self.auth = Some(Auth::RapidApi(key.into()));
+        self
+    }
+
+    /// Use an existing `reqwest::Client` instead of building one.
+    ///
+    /// The client's own proxy and user-agent settings apply; combining this
+    /// with [`proxy`](Self::proxy) or [`user_agent`](Self::user_agent) is
+    /// rejected by [`build`](Self::build).
+    #[must_use]
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http = Some(client);
+        self
+    }
+
+    /// Send every endpoint's requests to `url`, the full `query` endpoint
+    /// (e.g. `http://127.0.0.1:8080/query`), instead of the Alpha Vantage host.
+    #[must_use]
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    /// Abort each HTTP attempt after `timeout`. Timed-out attempts count as
+    /// transient failures and are retried under the retry policy.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Proxy behaviour of the internally built client (default: [`ProxyMode::System`]).
+    #[must_use]
+    pub fn proxy(mut self, mode: ProxyMode) -> Self {
+        self.proxy = Some(mode);
+        self
+    }
+
+    /// `User-Agent` header of the internally built client.
+    #[must_use]
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Request budgets of the key's plan (default: [`RateLimitTier::Unlimited`]).
+    ///
+    /// Free keys should set [`RateLimitTier::Free`] to queue requests instead
+    /// of running into Alpha Vantage's throttle.
+    #[must_use]
+    pub const fn rate_limit(mut self, tier: RateLimitTier) -> Self {
+        self.tier = tier;
+        self
+    }
+
+    /// Queue or fail when the per-minute budget is spent (default: queue).
+    #[must_use]
+    pub const fn when_rate_limited(mut self, when: WhenExhausted) -> Self {
+        self.when_exhausted = when;
+        self
+    }
+
+    /// Request realtime or delayed US market data.
+    #[must_use]
+    pub const fn entitlement(mut self, entitlement: Entitlement) -> Self {
+        self.entitlement = Some(entitlement);
+        self
+    }
+
+    /// Response format for history series (default: [`Datatype::Json`]).
+    #[must_use]
+    pub const fn datatype(mut self, datatype: Datatype) -> Self {
+        self.datatype = datatype;
+        self
+    }
+
+    /// Market currency for crypto symbols given without one, such as `BTC`
+    /// (default: `USD`). It must have known minor units: ISO codes, crypto
+    /// codes `paft_money` knows (`BTC`, `ETH`, `USDT`, ...), or a code
+    /// registered with [`borsa_core::set_currency_metadata`].
+    #[must_use]
+    pub fn crypto_market(mut self, market: impl Into<String>) -> Self {
+        self.crypto_market = Some(market.into());
+        self
+    }
+
+    /// Retry policy for transient failures (default: [`RetryPolicy::default`]).
+    #[must_use]
+    pub const fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Fill earnings revenue and net income from `INCOME_STATEMENT`, at one
+    /// extra request per earnings call (default: off).
+    #[must_use]
+    pub const fn earnings_income_statement(mut self, enabled: bool) -> Self {
+        self.earnings_income_statement = enabled;
+        self
+    }
+
+    /// Add `DIVIDENDS` and `SPLITS` records to daily, weekly and monthly
+    /// equity history with `include_actions`, at up to two extra requests per
+    /// history call (default: off). A failed lookup keeps the bare history.
+    #[must_use]
+    pub const fn history_actions(mut self, enabled: bool) -> Self {
+        self.history_actions = enabled;
+        self
+    }
+
+    /// Most `month` slices one intraday equity history call may request
+    /// (default: [`DEFAULT_MAX_INTRADAY_MONTHS`], enough for `Y2`).
+    /// Each slice is a separate request paced by the rate limiter, so a `Y10`
+    /// range needs about 121 and spends as much quota; wider windows are
+    /// `InvalidArg`.
+    ///
+    /// [`DEFAULT_MAX_INTRADAY_MONTHS`]: crate::window::DEFAULT_MAX_INTRADAY_MONTHS
+    #[must_use]
+    pub const fn max_intraday_months(mut self, months: usize) -> Self {
+        self.max_intraday_months = Some(months);
+        self
+    }
+
+    /// Build the connector.
+    ///
+    /// # Errors
+    /// Returns `BorsaError::InvalidArg` when no key was set, the base URL or
+    /// proxy URL does not parse, the user agent is not a valid header value,
+    /// the crypto market is empty or has no known minor units (such as
+    /// `BUSD`), or a proxy mode or user agent is combined with an external
+    /// HTTP client.
+    pub fn build(self) -> Result<AvConnector, BorsaError> {
+        let crypto_market = self.crypto_market.clone();
+        let history_actions = self.history_actions;
+        let adapter = self.build_adapter()?;
+        let mut connector =
+            AvConnector::from_adapter(&adapter).with_history_actions(history_actions);
+        if let Some(market) = crypto_market {
+            let market = market.trim().to_ascii_uppercase();
+            if market.is_empty() {
+                return Err(BorsaError::InvalidArg(
+                    "default crypto market must not be empty".into(),
+                ));
+            }
+            crate::convert::pricing_currency(&market)?;
+            connector.crypto_market = market;
+        }
+        Ok(connector)
+    }
+
+    fn build_adapter(self) -> Result<RealAdapter, BorsaError> {
+        let auth = self.auth.ok_or_else(|| {
+            BorsaError::InvalidArg("an Alpha Vantage or RapidAPI key is required".into())
+        })?;
+        let http = match self.http {
+            Some(_) if self.proxy.is_some() || self.user_agent.is_some() => {
+                return Err(BorsaError::InvalidArg(
+                    "proxy mode and user agent cannot be combined with an external HTTP client"
+                        .into(),
+                ));
+            }
+            Some(http) => http,
+            None => Self::make_client(self.proxy.unwrap_or_default(), self.user_agent)?,
+        };
+        let mut adapter = RealAdapter::from_auth(auth, http)
+            .with_rate_limiter(RateLimiter::new(self.tier, self.when_exhausted))
+            .with_retry_policy(self.retry)
+            .with_datatype(self.datatype)
+            .with_earnings_income_statement(self.earnings_income_statement);
+        if let Some(url) = self.base_url {
+            reqwest::Url::parse(&url)
+                .map_err(|e| BorsaError::InvalidArg(format!("invalid base URL '{url}': {e}")))?;
+            adapter = adapter.with_base_url(url);
+        }
+        if let Some(timeout) = self.timeout {
+            adapter = adapter.with_timeout(timeout);
+        }
+        if let Some(entitlement) = self.entitlement {
+            adapter = adapter.with_entitlement(entitlement);
+        }
+        if let Some(months) = self.max_intraday_months {
+            adapter = adapter.with_max_intraday_months(months);
+        }
+        Ok(adapter)
+    }
+
+    fn make_client(
+        proxy: ProxyMode,
+        user_agent: Option<String>,
+    ) -> Result<reqwest::Client, BorsaError> {
+        let mut builder = match proxy {
+            ProxyMode::System => reqwest::Client::builder(),
+            ProxyMode::Disabled => reqwest::Client::builder().no_proxy(),
+            ProxyMode::Url(url) => {
+                let proxy = reqwest::Proxy::all(&url).map_err(|e| {
+                    BorsaError::InvalidArg(format!("invalid proxy URL '{url}': {e}"))
+                })?;
+                reqwest::Client::builder().proxy(proxy)
+            }
+        };
+        if let Some(user_agent) = user_agent {
+            let value = reqwest::header::HeaderValue::from_str(&user_agent).map_err(|e| {
+                BorsaError::InvalidArg(format!("invalid user agent '{user_agent}': {e}"))
+            })?;
+            builder = builder.user_agent(value);
+        }
+        builder
+            .build()
+            .map_err(|e| BorsaError::connector("borsa-alphavantage", e.to_string()))
+    }
+}
@@ -1,10 +1,29 @@
 use std::str::FromStr;
 
-use alpha_vantage as av;
-use borsa_core::{self, AssetKind, Candle, Currency, HistoryMeta, HistoryResponse, Money};
-use chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc};
+use borsa_core::{
+    self, Action, Address, AssetKind, BalanceSheetRow, BorsaError, Candle, CashflowRow,
+    CompanyProfile, Currency, Earnings, EarningsQuarter, EarningsQuarterEps, EarningsYear,
+    FundKind, FundProfile, HistoryMeta, HistoryResponse, IncomeStatementRow, Money, Period,
+    Profile, Quote, Symbol,
+};
+use rust_decimal::Decimal;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
 use chrono_tz::Tz;
 
+use crate::api::bulk_quotes::BulkQuote;
+use crate::api::dividends::{Dividend, DividendsResponse};
+use crate::api::earnings::EarningsResponse;
+use crate::api::exchange_rate::ExchangeRate;
+use crate::api::overview::OverviewResponse;
+use crate::api::quote::GlobalQuote;
+use crate::api::splits::SplitsResponse;
+use crate::api::statements::{
+    BalanceSheetReport, CashFlowReport, IncomeStatementReport, IncomeStatementResponse,
+};
+use crate::api::time_series::{Bar, TimeSeriesResponse};
+use crate::window::HistoryWindow;
+
 fn round_non_negative_f64_to_u64_saturating(v: f64) -> u64 {
     if !v.is_finite() {
         return u64::MAX;
@@ -45,113 +64,385 @@ fn parse_ts(ts: &str, tz: Option<Tz>) -> Option<i64> {
     None
 }
 
+const USD: Currency = Currency::Iso(borsa_core::IsoCurrency::USD);
+
 fn usd_money(v: f64) -> Money {
-    Money::from_canonical_str(&v.to_string(), Currency::Iso(borsa_core::IsoCurrency::USD)).unwrap()
-}
-
-pub fn map_timeseries_to_history(ts: &av::stock_time::TimeSeries) -> HistoryResponse {
-    let tz = tz_from_opt(Some(ts.time_zone()));
-    let mut candles: Vec<Candle> = Vec::with_capacity(ts.data().len());
-    let mut actions = Vec::new();
-
-    for d in ts.data() {
-        if let Some(ts_sec) = parse_ts(d.time(), tz) {
-            candles.push(Candle {
-                ts: Utc.timestamp_opt(ts_sec, 0).unwrap(),
-                open: usd_money(d.open()),
-                high: usd_money(d.high()),
-                low: usd_money(d.low()),
-                close: usd_money(d.adjusted().unwrap_or_else(|| d.close())),
-                close_unadj: None,
-                volume: Some(d.volume()),
-            });
-            if let Some(div) = d.dividend()
-                && div > 0.0
-            {
-                actions.push(borsa_core::Action::Dividend {
-                    ts: Utc.timestamp_opt(ts_sec, 0).unwrap(),
-                    amount: usd_money(div),
-                });
-            }
+    Money::from_canonical_str(&v.to_string(), USD).unwrap()
+}
+
+fn money(v: f64, currency: &Currency) -> Result<Money, BorsaError> {
+    Ok(Money::from_canonical_str(&v.to_string(), currency.clone())?)
+}
+
+/// Parse a currency code as used by Alpha Vantage (`EUR`, `JPY`, `BTC`, ...).
+pub fn parse_currency(code: &str) -> Result<Currency, BorsaError> {
+    Currency::try_from_str(&code.trim().to_ascii_uppercase())
+        .map_err(|e| BorsaError::InvalidArg(format!("invalid currency code '{code}': {e}")))
+}
+
+/// Parse a currency that prices can be quoted in, i.e. one with a known
+/// number of minor units.
+///
+/// # Errors
+/// Returns `BorsaError::InvalidArg` for codes that do not parse and for
+/// codes such as `BUSD` that are neither ISO 4217 nor registered with
+/// `borsa_core::set_currency_metadata`, since `Money` cannot be built in them.
+pub fn pricing_currency(code: &str) -> Result<Currency, BorsaError> {
+    let currency = parse_currency(code)?;
+    currency.decimal_places().map_err(|_| {
+        BorsaError::InvalidArg(format!(
+            "unsupported market currency '{}': no known minor units",
+            code.trim().to_ascii_uppercase()
+        ))
+    })?;
+    Ok(currency)
+}
+
+/// Largest denominator tried when turning a split coefficient into a ratio.
+const MAX_SPLIT_DENOMINATOR: u32 = 1000;
+
+/// Alpha Vantage rounds split coefficients to 4 decimals, so `1.3333` is 4:3.
+const SPLIT_COEFFICIENT_TOLERANCE: f64 = 5e-5;
+
+/// Express a split coefficient (shares after per share before) as `numerator:denominator`.
+///
+/// An exact ratio wins. Otherwise the coefficient is taken at its 4-decimal
+/// precision: a reverse split prefers the roundest `1:N` that matches (so
+/// `0.0067` is 1:150, not 1:149), then the smallest denominator that does.
+/// Returns `None` for the no-split coefficient `1.0` and for values that are
+/// not finite, non-positive, or not a ratio of small integers.
+pub fn split_ratio(coefficient: f64) -> Option<(u32, u32)> {
+    if !coefficient.is_finite() || coefficient <= 0.0 || (coefficient - 1.0).abs() < 1e-9 {
+        return None;
+    }
+    let tolerance = SPLIT_COEFFICIENT_TOLERANCE * coefficient.max(1.0);
+    smallest_ratio_within(coefficient, 1e-9 * coefficient.max(1.0))
+        .or_else(|| {
+            (coefficient < 1.0)
+                .then(|| reverse_split(coefficient))
+                .flatten()
+        })
+        .or_else(|| smallest_ratio_within(coefficient, tolerance))
+}
+
+/// The ratio with the smallest denominator within `tolerance` of `coefficient`.
+fn smallest_ratio_within(coefficient: f64, tolerance: f64) -> Option<(u32, u32)> {
+    (1..=MAX_SPLIT_DENOMINATOR).find_map(|den| {
+        let rounded = (coefficient * f64::from(den)).round();
+        if (coefficient - rounded / f64::from(den)).abs() > tolerance || rounded < 1.0 {
+            return None;
         }
+        format!("{rounded:.0}")
+            .parse::<u32>()
+            .ok()
+            .map(|n| (n, den))
+    })
+}
+
+/// The roundest `1:N` whose coefficient rounds to `coefficient`, preferring
+/// multiples of 10, then of 5, then the closest `N`.
+fn reverse_split(coefficient: f64) -> Option<(u32, u32)> {
+    let low = (1.0 / (coefficient + SPLIT_COEFFICIENT_TOLERANCE)).ceil();
+    let high = (1.0 / (coefficient - SPLIT_COEFFICIENT_TOLERANCE)).floor();
+    if !(low.is_finite() && high.is_finite()) || high < low || high < 2.0 {
+        return None;
     }
+    let low = format!("{low:.0}").parse::<u32>().ok()?.max(2);
+    let high = format!("{high:.0}")
+        .parse::<u32>()
+        .ok()?
+        .min(MAX_SPLIT_DENOMINATOR);
+    let target = 1.0 / coefficient;
+    (low..=high)
+        .min_by(|a, b| {
+            let rank = |n: u32| u8::from(!n.is_multiple_of(10)) + u8::from(!n.is_multiple_of(5));
+            rank(*a).cmp(&rank(*b)).then_with(|| {
+                (f64::from(*a) - target)
+                    .abs()
+                    .total_cmp(&(f64::from(*b) - target).abs())
+            })
+        })
+        .map(|n| (1, n))
+}
 
-    candles.sort_by_key(|c| c.ts);
-    HistoryResponse {
-        candles,
-        actions,
-        adjusted: ts
-            .data()
-            .iter()
-            .any(|d| d.adjusted().is_some() || d.dividend().is_some()),
-        meta: Some(HistoryMeta {
-            timezone: tz_from_opt(Some(ts.time_zone())),
-            utc_offset_seconds: None,
-        }),
-    }
-}
-
-pub fn map_forex_to_history(fx: &av::forex::Forex) -> HistoryResponse {
-    let tz = tz_from_opt(Some(fx.time_zone()));
-    let mut candles: Vec<Candle> = Vec::with_capacity(fx.data().len());
-
-    for d in fx.data() {
-        if let Some(ts) = parse_ts(d.time(), tz) {
-            candles.push(Candle {
-                ts: Utc.timestamp_opt(ts, 0).unwrap(),
-                open: usd_money(d.open()),
-                high: usd_money(d.high()),
-                low: usd_money(d.low()),
-                close: usd_money(d.close()),
-                close_unadj: None,
-                volume: None,
-            });
+/// Ratio of adjusted to raw close, or `None` when the bar is not adjusted.
+fn adjustment_factor(close: f64, adjusted_close: Option<f64>) -> Option<f64> {
+    let adj = adjusted_close?;
+    (close > 0.0 && adj.is_finite()).then(|| adj / close)
+}
+
+/// Volume on the adjusted share basis: a 4:1 split quadruples earlier volumes.
+fn adjusted_volume(volume: f64, factor: Option<f64>) -> f64 {
+    match factor {
+        Some(f) if f > 0.0 => volume / f,
+        _ => volume,
+    }
+}
+
+fn split_action(ts: chrono::DateTime<Utc>, coefficient: Option<f64>) -> Option<borsa_core::Action> {
+    let (numerator, denominator) = split_ratio(coefficient?)?;
+    Some(borsa_core::Action::Split {
+        ts,
+        numerator,
+        denominator,
+    })
+}
+
+/// Accumulates candles and actions from bars quoted in one timezone and currency.
+struct SeriesBuilder<'a> {
+    tz: Option<Tz>,
+    currency: &'a Currency,
+    candles: Vec<Candle>,
+    actions: Vec<borsa_core::Action>,
+    adjusted: bool,
+}
+
+impl<'a> SeriesBuilder<'a> {
+    fn new(tz: Option<Tz>, currency: &'a Currency, capacity: usize) -> Self {
+        Self {
+            tz,
+            currency,
+            candles: Vec::with_capacity(capacity),
+            actions: Vec::new(),
+            adjusted: false,
         }
     }
-    candles.sort_by_key(|c| c.ts);
-
-    HistoryResponse {
-        candles,
-        actions: vec![],
-        adjusted: false,
-        meta: Some(HistoryMeta {
-            timezone: tz_from_opt(Some(fx.time_zone())),
-            utc_offset_seconds: None,
-        }),
-    }
-}
-
-pub fn map_crypto_to_history(c: &av::crypto::Crypto) -> HistoryResponse {
-    let tz = tz_from_opt(Some(c.time_zone()));
-    let mut candles: Vec<Candle> = Vec::with_capacity(c.data().len());
-
-    for d in c.data() {
-        if let Some(ts) = parse_ts(d.time(), tz) {
-            let vol_u64: u64 = round_non_negative_f64_to_u64_saturating(d.volume());
-            candles.push(Candle {
-                ts: Utc.timestamp_opt(ts, 0).unwrap(),
-                open: usd_money(d.open()),
-                high: usd_money(d.high()),
-                low: usd_money(d.low()),
-                close: usd_money(d.close()),
-                close_unadj: None,
-                volume: Some(vol_u64),
+
+    fn push(&mut self, time: &str, d: &Bar) -> Result<(), BorsaError> {
+        let currency = self.currency;
+        self.adjusted |= d.adjusted_close.is_some() || d.dividend_amount.is_some();
+        let Some(ts_sec) = parse_ts(time, self.tz) else {
+            return Ok(());
+        };
+        let ts = Utc.timestamp_opt(ts_sec, 0).unwrap();
+        let factor = adjustment_factor(d.close, d.adjusted_close);
+        let scale = |v: f64| factor.map_or(v, |f| v * f);
+        self.candles.push(Candle {
+            ts,
+            open: money(scale(d.open), currency)?,
+            high: money(scale(d.high), currency)?,
+            low: money(scale(d.low), currency)?,
+            close: money(d.adjusted_close.unwrap_or(d.close), currency)?,
+            close_unadj: d
+                .adjusted_close
+                .map(|_| money(d.close, currency))
+                .transpose()?,
+            volume: d
+                .volume
+                .map(|v| round_non_negative_f64_to_u64_saturating(adjusted_volume(v, factor))),
+        });
+        if let Some(div) = d.dividend_amount
+            && div > 0.0
+        {
+            self.actions.push(borsa_core::Action::Dividend {
+                ts,
+                amount: money(div, currency)?,
             });
         }
+        self.actions.extend(split_action(ts, d.split_coefficient));
+        Ok(())
     }
-    candles.sort_by_key(|c| c.ts);
 
-    HistoryResponse {
-        candles,
-        actions: vec![],
-        adjusted: false,
-        meta: Some(HistoryMeta {
-            timezone: tz_from_opt(Some(c.time_zone())),
-            utc_offset_seconds: None,
-        }),
+    fn finish(mut self, window: &HistoryWindow) -> HistoryResponse {
+        self.candles.sort_by_key(|c| c.ts);
+        let mut resp = HistoryResponse {
+            candles: self.candles,
+            actions: self.actions,
+            adjusted: self.adjusted,
+            meta: Some(HistoryMeta {
+                timezone: self.tz,
+                utc_offset_seconds: None,
+            }),
+        };
+        window.trim(&mut resp);
+        resp
     }
 }
 
+pub fn map_series_to_history(
+    ts: &TimeSeriesResponse,
+    currency: &Currency,
+    window: &HistoryWindow,
+) -> Result<HistoryResponse, BorsaError> {
+    let mut series = SeriesBuilder::new(tz_from_opt(ts.time_zone()), currency, ts.bars.len());
+    for (time, d) in &ts.bars {
+        series.push(time, d)?;
+    }
+    Ok(series.finish(window))
+}
+
+/// Most columns any Alpha Vantage time-series CSV carries.
+const MAX_CSV_COLUMNS: usize = 16;
+
+/// Column positions of a time-series CSV, read from its header row.
+struct CsvColumns {
+    timestamp: usize,
+    open: usize,
+    high: usize,
+    low: usize,
+    close: usize,
+    adjusted_close: Option<usize>,
+    volume: Option<usize>,
+    dividend_amount: Option<usize>,
+    split_coefficient: Option<usize>,
+}
+
+impl CsvColumns {
+    fn from_header(header: &str) -> Result<Self, BorsaError> {
+        let names: Vec<&str> = header.split(',').map(str::trim).collect();
+        let find = |name: &str| names.iter().position(|n| n.eq_ignore_ascii_case(name));
+        let require = |name: &str| {
+            find(name).ok_or_else(|| {
+                BorsaError::Data(format!(
+                    "time series CSV has no '{name}' column: '{header}'"
+                ))
+            })
+        };
+        if names.len() > MAX_CSV_COLUMNS {
+            return Err(BorsaError::Data(format!(
+                "time series CSV has too many columns: '{header}'"
+            )));
+        }
+        Ok(Self {
+            timestamp: find("time").map_or_else(|| require("timestamp"), Ok)?,
+            open: require("open")?,
+            high: require("high")?,
+            low: require("low")?,
+            close: require("close")?,
+            adjusted_close: find("adjusted_close").or_else(|| find("adjusted close")),
+            volume: find("volume"),
+            dividend_amount: find("dividend_amount").or_else(|| find("dividend amount")),
+            split_coefficient: find("split_coefficient").or_else(|| find("split coefficient")),
+        })
+    }
+
+    /// Split one data row into its timestamp and bar.
+    fn parse<'l>(&self, line: &'l str) -> Option<(&'l str, Bar)> {
+        let mut fields = [""; MAX_CSV_COLUMNS];
+        for (slot, field) in fields.iter_mut().zip(line.split(',')) {
+            *slot = field.trim();
+        }
+        let required = |i: usize| fields[i].parse::<f64>().ok();
+        let optional = |i: Option<usize>| {
+            i.and_then(|i| fields[i].parse::<f64>().ok())
+                .filter(|v| v.is_finite())
+        };
+        let time = fields[self.timestamp];
+        if time.is_empty() {
+            return None;
+        }
+        Some((
+            time,
+            Bar {
+                open: required(self.open)?,
+                high: required(self.high)?,
+                low: required(self.low)?,
+                close: required(self.close)?,
+                adjusted_close: optional(self.adjusted_close),
+                volume: optional(self.volume),
+                dividend_amount: optional(self.dividend_amount),
+                split_coefficient: optional(self.split_coefficient),
+            },
+        ))
+    }
+}
+
+/// Map a `datatype=csv` time series body, read row by row, into a `HistoryResponse`.
+///
+/// CSV bodies carry no metadata, so `time_zone` names the zone the timestamps
+/// are expressed in; it is interpreted exactly like the JSON `Time Zone` entry.
+pub fn map_csv_to_history(
+    body: &str,
+    time_zone: &str,
+    currency: &Currency,
+    window: &HistoryWindow,
+) -> Result<HistoryResponse, BorsaError> {
+    let mut lines = body
+        .lines()
+        .map(|l| l.trim_end_matches('\r'))
+        .filter(|l| !l.trim().is_empty());
+    let tz = tz_from_opt(Some(time_zone));
+    let Some(header) = lines.next() else {
+        return Ok(SeriesBuilder::new(tz, currency, 0).finish(window));
+    };
+    let columns = CsvColumns::from_header(header)?;
+    // The header is about as long as a data row, so this approximates the row count.
+    let capacity = body.len() / header.len().max(1);
+    let mut series = SeriesBuilder::new(tz, currency, capacity);
+    for (n, line) in lines.enumerate() {
+        let (time, bar) = columns.parse(line).ok_or_else(|| {
+            BorsaError::Data(format!("malformed time series CSV row {}: '{line}'", n + 2))
+        })?;
+        series.push(time, &bar)?;
+    }
+    Ok(series.finish(window))
+}
+
+pub fn map_global_quote(q: &GlobalQuote, now: DateTime<Utc>) -> Result<Quote, BorsaError> {
+    let sym = q.symbol.as_deref().unwrap_or_default();
+    let symbol =
+        Symbol::new(sym).map_err(|e| BorsaError::Data(format!("invalid symbol '{sym}': {e}")))?;
+    Ok(Quote {
+        symbol,
+        shortname: None,
+        price: q.price.map(usd_money),
+        previous_close: q.previous_close.map(usd_money),
+        exchange: None,
+        market_state: q
+            .trading_day()
+            .map(|_| crate::session::us_equity_session(now)),
+    })
+}
+
+pub fn map_bulk_quote(q: &BulkQuote, now: DateTime<Utc>) -> Result<Quote, BorsaError> {
+    let symbol = Symbol::new(&q.symbol)
+        .map_err(|e| BorsaError::Data(format!("invalid symbol '{}': {e}", q.symbol)))?;
+    Ok(Quote {
+        symbol,
+        shortname: None,
+        price: q.close.map(|v| money(v, &USD)).transpose()?,
+        previous_close: q.previous_close.map(|v| money(v, &USD)).transpose()?,
+        exchange: None,
+        market_state: q.time().map(|_| crate::session::us_equity_session(now)),
+    })
+}
+
+/// Map an exchange rate to a `Quote` priced in the quote currency.
+///
+/// `Money` rounds to the quote currency's minor units, so a USD/JPY rate of
+/// `151.237` is quoted as `151`; [`exact_exchange_rate`] keeps every digit.
+pub fn map_exchange_rate(rate: &ExchangeRate, symbol: &str) -> Result<Quote, BorsaError> {
+    let symbol = Symbol::new(symbol)
+        .map_err(|e| BorsaError::Data(format!("invalid symbol '{symbol}': {e}")))?;
+    let currency = parse_currency(&rate.to_code)?;
+    Ok(Quote {
+        symbol,
+        shortname: None,
+        price: rate.price().map(|p| money(p, &currency)).transpose()?,
+        previous_close: None,
+        exchange: None,
+        market_state: None,
+    })
+}
+
+/// The rate as a borsa `ExchangeRate`, at the precision Alpha Vantage sent
+/// rather than rounded to the quote currency's minor units.
+pub fn exact_exchange_rate(rate: &ExchangeRate) -> Result<borsa_core::ExchangeRate, BorsaError> {
+    let value = rate.price().ok_or_else(|| {
+        BorsaError::Data(format!(
+            "exchange rate {}/{} has no price",
+            rate.from_code, rate.to_code
+        ))
+    })?;
+    let value = Decimal::from_str(&value.to_string())
+        .map_err(|e| BorsaError::Data(format!("invalid exchange rate '{value}': {e}")))?;
+    Ok(borsa_core::ExchangeRate::new(
+        parse_currency(&rate.from_code)?,
+        parse_currency(&rate.to_code)?,
+        value,
+    )?)
+}
+
 pub fn map_kind_from_search_type(t: &str) -> AssetKind {
     match t.trim().to_ascii_uppercase().as_str() {
         "ETF" | "MUTUAL FUND" | "FUND" => AssetKind::Fund,
@@ -161,3 +452,257 @@ pub fn map_kind_from_search_type(t: &str) -> AssetKind {
         _ => AssetKind::Equity,
     }
 }
+
+/// Split Alpha Vantage's one-line `STREET, CITY, STATE, COUNTRY` address.
+///
+/// Lines with fewer parts keep the whole text as the street.
+fn map_address(line: &str, country: Option<&str>) -> Address {
+    let parts: Vec<&str> = line.split(',').map(str::trim).collect();
+    let mut address = Address {
+        country: country.map(str::to_string),
+        ..Address::default()
+    };
+    if let [street @ .., city, state, code] = parts.as_slice()
+        && !street.is_empty()
+    {
+        address.street1 = Some(street.join(", "));
+        address.city = Some((*city).to_string());
+        address.state = Some((*state).to_string());
+        if address.country.is_none() {
+            address.country = Some((*code).to_string());
+        }
+    } else {
+        address.street1 = Some(line.trim().to_string());
+    }
+    address
+}
+
+pub fn map_overview(o: &OverviewResponse) -> Result<Profile, BorsaError> {
+    let name = o
+        .name
+        .clone()
+        .ok_or_else(|| BorsaError::Data("OVERVIEW response has no name".into()))?;
+    let asset_type = o.asset_type.as_deref().unwrap_or_default();
+    if map_kind_from_search_type(asset_type) == AssetKind::Fund {
+        let kind = match asset_type.trim().to_ascii_uppercase().as_str() {
+            "MUTUAL FUND" => FundKind::MutualFund,
+            other => FundKind::from_str(other).unwrap_or_default(),
+        };
+        return Ok(Profile::Fund(FundProfile {
+            name,
+            family: None,
+            kind,
+            isin: None,
+        }));
+    }
+    Ok(Profile::Company(CompanyProfile {
+        name,
+        sector: o.sector.clone(),
+        industry: o.industry.clone(),
+        website: o.official_site.clone(),
+        address: o
+            .address
+            .as_deref()
+            .map(|a| map_address(a, o.country.as_deref())),
+        summary: o.description.clone(),
+        isin: None,
+    }))
+}
+
+/// Fiscal period end of a statement report.
+fn statement_period(fiscal_date_ending: &str) -> Result<Period, BorsaError> {
+    NaiveDate::parse_from_str(fiscal_date_ending, "%Y-%m-%d")
+        .map(Period::Date)
+        .map_err(|e| BorsaError::Data(format!("invalid fiscal date '{fiscal_date_ending}': {e}")))
+}
+
+/// Reported currency of a statement report; reports without one are USD.
+fn statement_currency(code: Option<&str>) -> Result<Currency, BorsaError> {
+    code.map_or(Ok(USD), |c| {
+        parse_currency(c).map_err(|e| BorsaError::Data(format!("statement currency: {e}")))
+    })
+}
+
+fn statement_money(v: Option<f64>, currency: &Currency) -> Result<Option<Money>, BorsaError> {
+    v.map(|v| money(v, currency)).transpose()
+}
+
+pub fn map_income_statement(r: &IncomeStatementReport) -> Result<IncomeStatementRow, BorsaError> {
+    let currency = statement_currency(r.reported_currency.as_deref())?;
+    Ok(IncomeStatementRow {
+        period: statement_period(&r.fiscal_date_ending)?,
+        total_revenue: statement_money(r.total_revenue, &currency)?,
+        gross_profit: statement_money(r.gross_profit, &currency)?,
+        operating_income: statement_money(r.operating_income, &currency)?,
+        net_income: statement_money(r.net_income, &currency)?,
+    })
+}
+
+pub fn map_balance_sheet(r: &BalanceSheetReport) -> Result<BalanceSheetRow, BorsaError> {
+    let currency = statement_currency(r.reported_currency.as_deref())?;
+    Ok(BalanceSheetRow {
+        period: statement_period(&r.fiscal_date_ending)?,
+        total_assets: statement_money(r.total_assets, &currency)?,
+        total_liabilities: statement_money(r.total_liabilities, &currency)?,
+        total_equity: statement_money(r.total_shareholder_equity, &currency)?,
+        cash: statement_money(r.cash_and_cash_equivalents_at_carrying_value, &currency)?,
+        long_term_debt: statement_money(r.long_term_debt, &currency)?,
+        shares_outstanding: r
+            .common_stock_shares_outstanding
+            .map(round_non_negative_f64_to_u64_saturating),
+    })
+}
+
+pub fn map_cash_flow(r: &CashFlowReport) -> Result<CashflowRow, BorsaError> {
+    let currency = statement_currency(r.reported_currency.as_deref())?;
+    Ok(CashflowRow {
+        period: statement_period(&r.fiscal_date_ending)?,
+        operating_cashflow: statement_money(r.operating_cashflow, &currency)?,
+        capital_expenditures: statement_money(r.capital_expenditures, &currency)?,
+        free_cash_flow: statement_money(r.free_cash_flow(), &currency)?,
+        net_income: statement_money(r.net_income, &currency)?,
+    })
+}
+
+/// Revenue and net income of the income statement report ending on `fiscal_date_ending`.
+///
+/// Reports with an unusable currency contribute nothing rather than failing
+/// the earnings they enrich.
+fn income_figures(
+    reports: &[IncomeStatementReport],
+    fiscal_date_ending: &str,
+) -> (Option<Money>, Option<Money>) {
+    let Some(r) = reports
+        .iter()
+        .find(|r| r.fiscal_date_ending == fiscal_date_ending)
+    else {
+        return (None, None);
+    };
+    let Ok(currency) = statement_currency(r.reported_currency.as_deref()) else {
+        return (None, None);
+    };
+    let figure = |v: Option<f64>| v.and_then(|v| money(v, &currency).ok());
+    (figure(r.total_revenue), figure(r.net_income))
+}
+
+/// Map `EARNINGS` into borsa's `Earnings`, joining revenue and net income from
+/// `income` by fiscal period end when given.
+pub fn map_earnings(e: &EarningsResponse, income: Option<&IncomeStatementResponse>) -> Earnings {
+    let (annual_reports, quarterly_reports) =
+        income.map_or((&[][..], &[][..]), |i| (i.reports(false), i.reports(true)));
+
+    let yearly = e
+        .annual_earnings
+        .iter()
+        .filter_map(|y| {
+            let year = y.fiscal_date_ending.get(..4)?.parse::<i32>().ok()?;
+            let (revenue, earnings) = income_figures(annual_reports, &y.fiscal_date_ending);
+            Some(EarningsYear {
+                year,
+                revenue,
+                earnings,
+            })
+        })
+        .collect();
+
+    let mut quarterly: Vec<EarningsQuarter> = Vec::with_capacity(e.quarterly_earnings.len());
+    let mut quarterly_eps: Vec<EarningsQuarterEps> = Vec::with_capacity(e.quarterly_earnings.len());
+    for q in &e.quarterly_earnings {
+        let Ok(period) = q.fiscal_date_ending.parse::<Period>() else {
+            continue;
+        };
+        quarterly_eps.push(EarningsQuarterEps {
+            period: period.clone(),
+            actual: q.reported_eps.map(usd_money),
+            estimate: q.estimated_eps.map(usd_money),
+        });
+        let (revenue, earnings) = income_figures(quarterly_reports, &q.fiscal_date_ending);
+        quarterly.push(EarningsQuarter {
+            period,
+            revenue,
+            earnings,
+        });
+    }
+
+    Earnings {
+        yearly,
+        quarterly,
+        quarterly_eps,
+    }
+}
+
+/// Parse an Alpha Vantage `YYYY-MM-DD` date.
+fn parse_date(s: Option<&str>) -> Option<NaiveDate> {
+    s.and_then(|d| NaiveDate::parse_from_str(d.trim(), "%Y-%m-%d").ok())
+}
+
+/// Keep dividends with an ex-dividend date and a positive amount.
+pub fn map_dividends(r: &DividendsResponse) -> Vec<Dividend> {
+    r.data
+        .iter()
+        .filter_map(|d| {
+            Some(Dividend {
+                ex_date: parse_date(d.ex_dividend_date.as_deref())?,
+                declaration_date: parse_date(d.declaration_date.as_deref()),
+                record_date: parse_date(d.record_date.as_deref()),
+                payment_date: parse_date(d.payment_date.as_deref()),
+                amount: d.amount.filter(|a| *a > 0.0)?,
+            })
+        })
+        .collect()
+}
+
+/// Midnight US/Eastern on `day`, the timestamp of daily equity bars.
+fn us_equity_day_start(day: NaiveDate) -> Result<DateTime<Utc>, BorsaError> {
+    crate::session::US_EQUITY_TZ
+        .from_local_datetime(&day.and_time(chrono::NaiveTime::MIN))
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok_or_else(|| BorsaError::Data(format!("no US/Eastern midnight on {day}")))
+}
+
+pub fn dividend_action(d: &Dividend) -> Result<Action, BorsaError> {
+    Ok(Action::Dividend {
+        ts: us_equity_day_start(d.ex_date)?,
+        amount: money(d.amount, &USD)?,
+    })
+}
+
+pub fn map_splits(r: &SplitsResponse) -> Result<Vec<Action>, BorsaError> {
+    let mut actions = Vec::with_capacity(r.data.len());
+    for s in &r.data {
+        let Some(day) = parse_date(s.effective_date.as_deref()) else {
+            continue;
+        };
+        actions.extend(split_action(us_equity_day_start(day)?, s.split_factor));
+    }
+    Ok(actions)
+}
+
+const fn action_ts(a: &Action) -> DateTime<Utc> {
+    match a {
+        Action::Dividend { ts, .. } | Action::Split { ts, .. } | Action::CapitalGain { ts, .. } => {
+            *ts
+        }
+    }
+}
+
+/// Add `actions` to a non-empty `resp`, keep those inside `window`, and
+/// order every action by time.
+///
+/// An added split replaces one the series already derived for the same time.
+pub fn merge_actions(resp: &mut HistoryResponse, actions: Vec<Action>, window: &HistoryWindow) {
+    if resp.candles.is_empty() {
+        return;
+    }
+    let split_at: Vec<DateTime<Utc>> = actions
+        .iter()
+        .filter(|a| matches!(a, Action::Split { .. }))
+        .map(action_ts)
+        .collect();
+    resp.actions
+        .retain(|a| !matches!(a, Action::Split { .. }) || !split_at.contains(&action_ts(a)));
+    resp.actions.extend(actions);
+    window.trim(resp);
+    resp.actions.sort_by_key(action_ts);
+}
@@ -0,0 +1,237 @@
+use std::time::Duration;
+
+use borsa_core::BorsaError;
+use chrono::{Days, Utc};
+
+const CONNECTOR: &str = "borsa-alphavantage";
+const RATE_LIMITED: &str = "rate limited";
+const INVALID_KEY: &str = "invalid API key: ";
+const PREMIUM: &str = "premium Alpha Vantage endpoint: ";
+const HTTP_STATUS: &str = "HTTP status ";
+const TRANSPORT: &str = "HTTP transport error: ";
+
+/// Failure classes Alpha Vantage reports through its `Note`, `Information`
+/// and `Error Message` envelopes.
+///
+/// Converts into the closest [`BorsaError`] so the orchestrator can route on
+/// it: throttling and key problems become connector errors, premium-only
+/// endpoints become `Unsupported` and unknown symbols `NotFound`.
+/// [`AvError::from_borsa`] recovers the class from a converted error.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum AvError {
+    /// The key's request budget is spent.
+    #[error("rate limited{}: {message}", retry_suffix(*.retry_after))]
+    RateLimited {
+        /// How long until a retry can succeed, when known.
+        retry_after: Option<Duration>,
+        /// Message returned by Alpha Vantage.
+        message: String,
+    },
+    /// The endpoint or parameter needs a premium subscription.
+    #[error("premium endpoint required: {message}")]
+    PremiumRequired {
+        /// Message returned by Alpha Vantage.
+        message: String,
+    },
+    /// The API key is missing, malformed or the shared demo key.
+    #[error("{INVALID_KEY}{message}")]
+    InvalidKey {
+        /// Message returned by Alpha Vantage.
+        message: String,
+    },
+    /// Alpha Vantage has no data for the request, usually an unknown symbol.
+    #[error("not found: {what}")]
+    NotFound {
+        /// What was looked up.
+        what: String,
+    },
+    /// The server answered with a non-success status, so the body is not an
+    /// Alpha Vantage response and says nothing about the symbol.
+    #[error("{HTTP_STATUS}{status}")]
+    HttpStatus {
+        /// HTTP status code.
+        status: u16,
+    },
+    /// The request did not complete.
+    #[error("{TRANSPORT}{message}")]
+    Transport {
+        /// Transport error message.
+        message: String,
+    },
+    /// Any other message returned in place of data.
+    #[error("{message}")]
+    Api {
+        /// Message returned by Alpha Vantage.
+        message: String,
+    },
+}
+
+fn retry_suffix(retry_after: Option<Duration>) -> String {
+    retry_after.map_or_else(String::new, |d| format!(" (retry after {}s)", d.as_secs()))
+}
+
+impl AvError {
+    /// Classify the message found under the envelope `key` of a response body.
+    #[must_use]
+    pub fn from_envelope(key: &str, message: &str) -> Self {
+        match key {
+            "Note" => Self::rate_limited(message),
+            "Error Message" if is_invalid_key(message) => Self::InvalidKey {
+                message: message.to_string(),
+            },
+            "Error Message" => Self::NotFound {
+                what: message.to_string(),
+            },
+            _ => Self::from_message(message),
+        }
+    }
+
+    /// Classify a bare message whose envelope is unknown.
+    #[must_use]
+    pub fn from_message(message: &str) -> Self {
+        if let Some(typed) = Self::parse_typed(message) {
+            return typed;
+        }
+        let m = message.to_ascii_lowercase();
+        if m.contains("rate limit") || m.contains(" per minute") || m.contains(" per day") {
+            Self::rate_limited(message)
+        } else if m.contains("premium") {
+            Self::PremiumRequired {
+                message: message.to_string(),
+            }
+        } else if is_invalid_key(message) {
+            Self::InvalidKey {
+                message: message.to_string(),
+            }
+        } else if m.contains("invalid api call")
+            || m.contains("no data")
+            || m.contains("not found")
+            || m.contains("unknown symbol")
+            || m.contains("no matches")
+        {
+            Self::NotFound {
+                what: message.to_string(),
+            }
+        } else {
+            Self::Api {
+                message: message.to_string(),
+            }
+        }
+    }
+
+    /// Recover the class of an error previously converted into `BorsaError`.
+    #[must_use]
+    pub fn from_borsa(err: &BorsaError) -> Option<Self> {
+        match err {
+            BorsaError::Connector { connector, msg } if connector == CONNECTOR => {
+                Self::parse_typed(msg)
+            }
+            BorsaError::Unsupported { capability } => {
+                capability
+                    .strip_prefix(PREMIUM)
+                    .map(|message| Self::PremiumRequired {
+                        message: message.to_string(),
+                    })
+            }
+            BorsaError::NotFound { what } => Some(Self::NotFound { what: what.clone() }),
+            _ => None,
+        }
+    }
+
+    /// Suggested wait before retrying, for rate-limited errors.
+    #[must_use]
+    pub const fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimited { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// Whether this is a spent daily budget, which no retry within the day can fix.
+    #[must_use]
+    pub fn is_daily_limit(&self) -> bool {
+        matches!(self, Self::RateLimited { message, .. } if is_daily(message))
+    }
+
+    pub(crate) fn rate_limited(message: &str) -> Self {
+        let retry_after = if is_daily(message) {
+            Some(until_next_utc_midnight())
+        } else {
+            Some(Duration::from_mins(1))
+        };
+        Self::RateLimited {
+            retry_after,
+            message: message.to_string(),
+        }
+    }
+
+    /// Parse the message layout produced by `Display`.
+    fn parse_typed(msg: &str) -> Option<Self> {
+        if let Some(rest) = msg.strip_prefix(RATE_LIMITED) {
+            let (retry_after, message) = match rest.strip_prefix(" (retry after ") {
+                Some(r) => {
+                    let (secs, message) = r.split_once("s): ")?;
+                    (Some(Duration::from_secs(secs.parse().ok()?)), message)
+                }
+                None => (None, rest.strip_prefix(": ")?),
+            };
+            return Some(Self::RateLimited {
+                retry_after,
+                message: message.to_string(),
+            });
+        }
+        if let Some(status) = msg.strip_prefix(HTTP_STATUS) {
+            return status
+                .parse()
+                .ok()
+                .map(|status| Self::HttpStatus { status });
+        }
+        if let Some(message) = msg.strip_prefix(TRANSPORT) {
+            return Some(Self::Transport {
+                message: message.to_string(),
+            });
+        }
+        msg.strip_prefix(INVALID_KEY)
+            .map(|message| Self::InvalidKey {
+                message: message.to_string(),
+            })
+    }
+}
+
+fn is_invalid_key(message: &str) -> bool {
+    let m = message.to_ascii_lowercase();
+    (m.contains("apikey") || m.contains("api key"))
+        && (m.contains("invalid") || m.contains("missing") || m.contains("demo"))
+}
+
+fn is_daily(message: &str) -> bool {
+    let m = message.to_ascii_lowercase();
+    m.contains("per day") && !m.contains("per minute")
+}
+
+fn until_next_utc_midnight() -> Duration {
+    let now = Utc::now();
+    now.date_naive()
+        .checked_add_days(Days::new(1))
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|midnight| (midnight.and_utc() - now).num_seconds())
+        .and_then(|secs| u64::try_from(secs).ok())
+        .map_or(Duration::from_hours(24), Duration::from_secs)
+}
+
+impl From<AvError> for BorsaError {
+    fn from(e: AvError) -> Self {
+        match e {
+            AvError::NotFound { what } => Self::not_found(what),
+            AvError::PremiumRequired { message } => {
+                Self::unsupported(format!("{PREMIUM}{message}"))
+            }
+            AvError::Api { message } => Self::connector(CONNECTOR, message),
+            other @ (AvError::RateLimited { .. }
+            | AvError::InvalidKey { .. }
+            | AvError::HttpStatus { .. }
+            | AvError::Transport { .. }) => Self::connector(CONNECTOR, other.to_string()),
+        }
+    }
+}
@@ -1,30 +1,54 @@
 //! Alpha Vantage connector for borsa.
 //!
-//! Provides quotes, history, search, and earnings via the `BorsaConnector` interface.
+//! Provides quotes, history, search, earnings, financial statements, profiles, dividends, and splits via the `BorsaConnector` interface.
 //!
 //! Lightweight adapter helpers used in tests are behind the optional
 //! `test-adapters` feature.
 #![warn(missing_docs)]
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use async_trait::async_trait;
 
 use borsa_core::{
-    AssetKind, BorsaError, HistoryRequest, HistoryResponse, Instrument, Quote, SearchRequest,
+    Action, AssetKind, BalanceSheetRow, BorsaError, CashflowRow, ExchangeRate, HistoryRequest,
+    HistoryResponse, IncomeStatementRow, Instrument, Interval, Profile, Quote, SearchRequest,
     SearchResponse,
     connector::{
-        BorsaConnector, ConnectorKey, /*EarningsProvider,*/ HistoryProvider, QuoteProvider,
-        SearchProvider,
+        BalanceSheetProvider, BorsaConnector, CashflowProvider, ConnectorKey, EarningsProvider,
+        HistoryProvider, IncomeStatementProvider, ProfileProvider, QuoteProvider, SearchProvider,
     },
 };
 
-/// Adapter layer that wraps the `alpha_vantage` client and exposes small async traits.
+/// Adapter layer that wraps the Alpha Vantage API client and exposes small async traits.
 pub mod adapter;
+pub mod api;
+mod builder;
 mod convert;
+/// Typed Alpha Vantage failures recognised from its response envelopes.
+pub mod error;
+/// Client-side request budgets matched to the Alpha Vantage key tier.
+pub mod rate_limit;
+/// Aggregation of monthly bars into coarser intervals.
+pub mod resample;
+/// Backoff policy for transient request failures.
+pub mod retry;
+/// US equity trading-session classification for intraday bars and quotes.
+pub mod session;
+/// Resolution of history request ranges and periods into concrete time bounds.
+pub mod window;
 
 #[cfg(feature = "test-adapters")]
 use adapter::CloneArcAdapters;
-use adapter::{/*AvEarnings,*/ AvHistory, AvQuotes, AvSearch, RealAdapter};
+#[cfg(not(feature = "test-adapters"))]
+use adapter::RealAdapter;
+use adapter::{
+    AvCorporateActions, AvEarnings, AvFundamentals, AvHistory, AvProfile, AvQuotes, AvSearch,
+};
+use api::dividends::Dividend;
+use error::AvError;
+
+pub use builder::{AvConnectorBuilder, Datatype, Entitlement, ProxyMode};
 
 #[cfg(not(feature = "test-adapters"))]
 type AdapterArc = Arc<RealAdapter>;
@@ -44,44 +68,76 @@ type SearchAdapter = Arc<dyn AvSearch>;
 #[cfg(not(feature = "test-adapters"))]
 type SearchAdapter = AdapterArc;
 
-/*
 #[cfg(feature = "test-adapters")]
 type EarningsAdapter = Arc<dyn AvEarnings>;
 #[cfg(not(feature = "test-adapters"))]
 type EarningsAdapter = AdapterArc;
-*/
+
+#[cfg(feature = "test-adapters")]
+type FundamentalsAdapter = Arc<dyn AvFundamentals>;
+#[cfg(not(feature = "test-adapters"))]
+type FundamentalsAdapter = AdapterArc;
+
+#[cfg(feature = "test-adapters")]
+type ProfileAdapter = Arc<dyn AvProfile>;
+#[cfg(not(feature = "test-adapters"))]
+type ProfileAdapter = AdapterArc;
+
+#[cfg(feature = "test-adapters")]
+type ActionsAdapter = Arc<dyn AvCorporateActions>;
+#[cfg(not(feature = "test-adapters"))]
+type ActionsAdapter = AdapterArc;
 
 /// Public connector implementation backed by Alpha Vantage APIs.
 pub struct AvConnector {
     quotes: QuotesAdapter,
     history: HistoryAdapter,
     search: SearchAdapter,
-    /* earnings: EarningsAdapter, */
+    earnings: EarningsAdapter,
+    fundamentals: FundamentalsAdapter,
+    profile: ProfileAdapter,
+    actions: ActionsAdapter,
+    crypto_market: String,
+    history_actions: bool,
 }
 
+const DEFAULT_CRYPTO_MARKET: &str = "USD";
+
 impl AvConnector {
+    /// Start configuring a connector.
+    #[must_use]
+    pub fn builder() -> AvConnectorBuilder {
+        AvConnectorBuilder::default()
+    }
+
+    /// Finish a constructor shorthand, whose settings cannot fail validation.
+    fn from_defaults(builder: AvConnectorBuilder) -> Self {
+        builder
+            .build()
+            .expect("default Alpha Vantage connector settings are valid")
+    }
+
     /// Use the native Alpha Vantage API key.
+    ///
+    /// Shorthand for [`AvConnector::builder`] with `api_key`; the system proxy
+    /// is honoured.
     pub fn new_with_key(key: impl Into<String>) -> Self {
-        let a = RealAdapter::new_with_key(key);
-        Self::from_adapter(&a)
+        Self::from_defaults(Self::builder().api_key(key))
     }
 
     /// Use a `RapidAPI` key for Alpha Vantage.
     pub fn new_with_rapidapi(key: impl Into<String>) -> Self {
-        let a = RealAdapter::new_with_rapidapi(key);
-        Self::from_adapter(&a)
+        Self::from_defaults(Self::builder().rapidapi_key(key))
     }
 
     /// Use the native Alpha Vantage API key with an external `reqwest::Client`.
     pub fn new_with_key_and_client(key: impl Into<String>, http: reqwest::Client) -> Self {
-        let a = RealAdapter::new_with_key_and_client(key, http);
-        Self::from_adapter(&a)
+        Self::from_defaults(Self::builder().api_key(key).http_client(http))
     }
 
     /// Use a `RapidAPI` key with an external `reqwest::Client`.
     pub fn new_with_rapidapi_and_client(key: impl Into<String>, http: reqwest::Client) -> Self {
-        let a = RealAdapter::new_with_rapidapi_and_client(key, http);
-        Self::from_adapter(&a)
+        Self::from_defaults(Self::builder().rapidapi_key(key).http_client(http))
     }
 
     /// For tests/injection.
@@ -92,7 +148,12 @@ impl AvConnector {
             quotes: adapter.clone_arc_quotes(),
             history: adapter.clone_arc_history(),
             search: adapter.clone_arc_search(),
-            /* earnings: adapter.clone_arc_earnings(), */
+            earnings: adapter.clone_arc_earnings(),
+            fundamentals: adapter.clone_arc_fundamentals(),
+            profile: adapter.clone_arc_profile(),
+            actions: adapter.clone_arc_actions(),
+            crypto_market: DEFAULT_CRYPTO_MARKET.to_string(),
+            history_actions: false,
         }
     }
 
@@ -105,87 +166,278 @@ impl AvConnector {
             quotes: Arc::clone(&shared),
             history: Arc::clone(&shared),
             search: Arc::clone(&shared),
-            /* earnings: shared, */
+            earnings: Arc::clone(&shared),
+            fundamentals: Arc::clone(&shared),
+            profile: Arc::clone(&shared),
+            actions: shared,
+            crypto_market: DEFAULT_CRYPTO_MARKET.to_string(),
+            history_actions: false,
         }
     }
 
+    /// Add `DIVIDENDS` and `SPLITS` records to daily, weekly and monthly
+    /// equity history with `include_actions`, at up to two extra requests per
+    /// history call (default: off; also [`AvConnectorBuilder::history_actions`]).
+    /// Auto-adjusted daily history already carries both and skips the lookups.
+    #[must_use]
+    pub const fn with_history_actions(mut self, enabled: bool) -> Self {
+        self.history_actions = enabled;
+        self
+    }
+
     /// Static connector key used in orchestrator priority configuration.
     pub const KEY: ConnectorKey = ConnectorKey::new("borsa-alphavantage");
 
-    fn looks_like_not_found(msg: &str) -> bool {
-        let m = msg.to_ascii_lowercase();
-        m.contains("invalid api call")
-            || m.contains("no data")
-            || m.contains("not found")
-            || m.contains("unknown symbol")
-            || m.contains("no matches")
-    }
-
-    fn normalize_error(e: BorsaError, what: &str) -> BorsaError {
-        match e {
-            BorsaError::Connector { connector, msg } => {
-                if Self::looks_like_not_found(&msg) {
-                    BorsaError::not_found(what.to_string())
-                } else {
-                    BorsaError::Connector { connector, msg }
+    /// Most symbols `REALTIME_BULK_QUOTES` accepts per request.
+    pub const BULK_QUOTE_LIMIT: usize = 100;
+
+    /// Quote many US equities through `REALTIME_BULK_QUOTES` (premium), one
+    /// request per [`BULK_QUOTE_LIMIT`](Self::BULK_QUOTE_LIMIT) distinct symbols.
+    ///
+    /// Returns one result per instrument, in input order. Symbols the response
+    /// leaves out are `NotFound`, forex and crypto pairs are `Unsupported`, a
+    /// malformed row is a `Data` error for its own symbol only, and a failed
+    /// request reports its error on every instrument of that chunk.
+    pub async fn quotes(&self, instruments: &[Instrument]) -> Vec<Result<Quote, BorsaError>> {
+        let is_pair = |i: &Instrument| matches!(i.kind(), AssetKind::Forex | AssetKind::Crypto);
+        let mut seen = HashSet::new();
+        let symbols: Vec<&str> = instruments
+            .iter()
+            .filter(|i| !is_pair(i))
+            .map(Instrument::symbol_str)
+            .filter(|s| seen.insert(s.to_ascii_uppercase()))
+            .collect();
+
+        let mut found: HashMap<String, Result<Quote, BorsaError>> = HashMap::new();
+        for chunk in symbols.chunks(Self::BULK_QUOTE_LIMIT) {
+            match self.quotes.quote_bulk(chunk).await {
+                Ok(rows) => {
+                    for (symbol, q) in rows {
+                        found.entry(symbol.trim().to_ascii_uppercase()).or_insert(q);
+                    }
+                }
+                Err(e) => {
+                    let e = Self::normalize_error(e, "bulk quotes");
+                    for s in chunk {
+                        found.insert(s.to_ascii_uppercase(), Err(e.clone()));
+                    }
                 }
             }
-            BorsaError::Other(msg) => {
-                if Self::looks_like_not_found(&msg) {
-                    BorsaError::not_found(what.to_string())
-                } else {
-                    BorsaError::connector("borsa-alphavantage", msg)
+        }
+
+        instruments
+            .iter()
+            .map(|i| {
+                if is_pair(i) {
+                    return Err(BorsaError::unsupported("bulk quote/pair"));
                 }
+                let mut quote = found
+                    .get(&i.symbol_str().to_ascii_uppercase())
+                    .cloned()
+                    .unwrap_or_else(|| {
+                        Err(BorsaError::not_found(format!("quote for {}", i.symbol())))
+                    })?;
+                quote.symbol = i.symbol().clone();
+                Ok(quote)
+            })
+            .collect()
+    }
+
+    /// Realtime rate of a forex or crypto pair from `CURRENCY_EXCHANGE_RATE`,
+    /// at the precision Alpha Vantage sends.
+    ///
+    /// [`quote`](QuoteProvider::quote) prices pairs as `Money`, which rounds
+    /// to the quote currency's minor units (USD/JPY `151.237` becomes `151`);
+    /// this keeps every digit.
+    ///
+    /// # Errors
+    /// Instruments that are not forex or crypto pairs are `Unsupported`,
+    /// malformed pairs are `InvalidArg`, and request failures are classified
+    /// like every other provider call.
+    pub async fn exchange_rate(&self, instrument: &Instrument) -> Result<ExchangeRate, BorsaError> {
+        let (from, to) = match instrument.kind() {
+            AssetKind::Forex => Self::parse_forex_pair(instrument.symbol_str())?,
+            AssetKind::Crypto => self.parse_crypto_pair(instrument.symbol_str())?,
+            _ => return Err(BorsaError::unsupported("exchange_rate/equity")),
+        };
+        self.quotes.exchange_rate(from, to).await.map_err(|e| {
+            Self::normalize_error(e, &format!("exchange rate for {}", instrument.symbol()))
+        })
+    }
+
+    /// Full dividend history of a US equity from `DIVIDENDS`, most recent
+    /// first, with ex-dividend, declaration, record and payment dates.
+    ///
+    /// borsa-core has no dividend provider hook, so this is an inherent method.
+    ///
+    /// # Errors
+    /// Forex and crypto pairs are `Unsupported`; request failures are
+    /// classified like every other provider call.
+    pub async fn dividends(&self, instrument: &Instrument) -> Result<Vec<Dividend>, BorsaError> {
+        if matches!(instrument.kind(), AssetKind::Forex | AssetKind::Crypto) {
+            return Err(BorsaError::unsupported("dividends/pair"));
+        }
+        self.actions
+            .dividends(instrument.symbol_str())
+            .await
+            .map_err(|e| {
+                Self::normalize_error(e, &format!("dividends for {}", instrument.symbol()))
+            })
+    }
+
+    /// Split history of a US equity from `SPLITS` as `Action::Split`
+    /// entries with exact ratios (a `1.5` factor becomes 3:2), most recent first.
+    ///
+    /// borsa-core has no split provider hook, so this is an inherent method.
+    ///
+    /// # Errors
+    /// Forex and crypto pairs are `Unsupported`; request failures are
+    /// classified like every other provider call.
+    pub async fn splits(&self, instrument: &Instrument) -> Result<Vec<Action>, BorsaError> {
+        if matches!(instrument.kind(), AssetKind::Forex | AssetKind::Crypto) {
+            return Err(BorsaError::unsupported("splits/pair"));
+        }
+        self.actions
+            .splits(instrument.symbol_str())
+            .await
+            .map_err(|e| Self::normalize_error(e, &format!("splits for {}", instrument.symbol())))
+    }
+
+    /// Add dividends and splits the series itself does not carry to daily,
+    /// weekly and monthly equity history, when enabled.
+    ///
+    /// Adjusted series already carry dividends, and the daily adjusted series
+    /// also splits; intraday bars carry no actions. The extra records only
+    /// enrich the candles already fetched, so a failed lookup keeps the bare
+    /// history.
+    async fn fill_actions(
+        &self,
+        instrument: &Instrument,
+        req: &HistoryRequest,
+        resp: &mut HistoryResponse,
+    ) {
+        if !self.history_actions || !req.include_actions() || req.interval().is_intraday() {
+            return;
+        }
+        let mut actions = Vec::new();
+        if !req.auto_adjust()
+            && let Ok(dividends) = self.dividends(instrument).await
+        {
+            actions.extend(dividends.iter().filter_map(|d| d.to_action().ok()));
+        }
+        if !(req.auto_adjust() && req.interval() == Interval::D1)
+            && let Ok(splits) = self.splits(instrument).await
+        {
+            actions.extend(splits);
+        }
+        convert::merge_actions(resp, actions, &window::HistoryWindow::from_request(req));
+    }
+
+    /// Classify untyped adapter errors and attach `what` to not-found errors.
+    ///
+    /// The real adapter already returns typed errors: envelope text is
+    /// classified when the body is decoded, and HTTP statuses and transport
+    /// failures keep their own class. Only bare messages from other adapters
+    /// are matched here.
+    fn normalize_error(e: BorsaError, what: &str) -> BorsaError {
+        let e = match e {
+            e @ BorsaError::Connector { .. } if AvError::from_borsa(&e).is_some() => e,
+            BorsaError::Connector { msg, .. } | BorsaError::Other(msg) => {
+                AvError::from_message(&msg).into()
             }
             other => other,
+        };
+        match e {
+            BorsaError::NotFound { .. } => BorsaError::not_found(what.to_string()),
+            other => other,
+        }
+    }
+
+    /// Split `BASE/QUOTE` or `BASE-QUOTE`, or `None` when neither delimiter is present.
+    fn split_pair<'a>(
+        symbol: &'a str,
+        what: &str,
+    ) -> Result<Option<(&'a str, &'a str)>, BorsaError> {
+        let Some(pos) = symbol.find('/').or_else(|| symbol.find('-')) else {
+            return Ok(None);
+        };
+        let base = &symbol[..pos];
+        let quote = &symbol[pos + 1..];
+        if base.is_empty() || quote.is_empty() {
+            return Err(BorsaError::InvalidArg(format!(
+                "Invalid {what} pair format: '{symbol}' - empty base or quote currency"
+            )));
         }
+        Ok(Some((base, quote)))
     }
 
     /// Parse a forex symbol into base and quote currencies.
     /// Requires explicit delimiters: EUR/USD, BTC/USDT, etc.
     fn parse_forex_pair(symbol: &str) -> Result<(&str, &str), BorsaError> {
-        // Try to split on common delimiters
-        if let Some(pos) = symbol.find('/') {
-            let base = &symbol[..pos];
-            let quote = &symbol[pos + 1..];
-            if base.is_empty() || quote.is_empty() {
-                return Err(BorsaError::InvalidArg(format!(
-                    "Invalid forex pair format: '{symbol}' - empty base or quote currency"
-                )));
-            }
-            return Ok((base, quote));
-        }
+        Self::split_pair(symbol, "forex")?.ok_or_else(|| {
+            BorsaError::InvalidArg(format!(
+                "Forex pair for AlphaVantage must be in 'BASE/QUOTE' format, got: '{symbol}'"
+            ))
+        })
+    }
 
-        if let Some(pos) = symbol.find('-') {
-            let base = &symbol[..pos];
-            let quote = &symbol[pos + 1..];
-            if base.is_empty() || quote.is_empty() {
-                return Err(BorsaError::InvalidArg(format!(
-                    "Invalid forex pair format: '{symbol}' - empty base or quote currency"
-                )));
+    /// Parse a crypto symbol into the digital currency and its market currency.
+    /// Accepts BTC/EUR and ETH-JPY; bare symbols like BTC are priced in the
+    /// configured default market (USD unless set on the builder). An explicit
+    /// market that prices cannot be built in is rejected before any request.
+    fn parse_crypto_pair<'a>(&'a self, symbol: &'a str) -> Result<(&'a str, &'a str), BorsaError> {
+        match Self::split_pair(symbol, "crypto")? {
+            Some((base, market)) => {
+                convert::pricing_currency(market)?;
+                Ok((base, market))
             }
-            return Ok((base, quote));
+            None => Ok((symbol, self.crypto_market.as_str())),
         }
-
-        // No delimiter found - require explicit format
-        Err(BorsaError::InvalidArg(format!(
-            "Forex pair for AlphaVantage must be in 'BASE/QUOTE' format, got: '{symbol}'"
-        )))
     }
 }
 
 #[async_trait]
 impl QuoteProvider for AvConnector {
     async fn quote(&self, instrument: &Instrument) -> Result<Quote, BorsaError> {
-        self.quotes
-            .quote_equity(instrument.symbol_str())
+        let what = format!("quote for {}", instrument.symbol());
+        let pair = match instrument.kind() {
+            AssetKind::Forex => Some(Self::parse_forex_pair(instrument.symbol_str())?),
+            AssetKind::Crypto => Some(self.parse_crypto_pair(instrument.symbol_str())?),
+            _ => None,
+        };
+        let Some((from, to)) = pair else {
+            return self
+                .quotes
+                .quote_equity(instrument.symbol_str())
+                .await
+                .map_err(|e| Self::normalize_error(e, &what));
+        };
+        let mut quote = self
+            .quotes
+            .quote_pair(from, to)
             .await
-            .map_err(|e| Self::normalize_error(e, &format!("quote for {}", instrument.symbol())))
+            .map_err(|e| Self::normalize_error(e, &what))?;
+        // Report the pair under the caller's spelling (`EUR-USD`, bare `BTC`, ...).
+        quote.symbol = instrument.symbol().clone();
+        Ok(quote)
     }
 }
 
 #[async_trait]
 impl HistoryProvider for AvConnector {
+    /// Fetch history for `instrument`.
+    ///
+    /// `include_actions` alone returns only the actions the series itself
+    /// carries: dividends on adjusted series and splits on the daily adjusted
+    /// series. Dividends and splits for the other daily, weekly and monthly
+    /// equity series need [`AvConnectorBuilder::history_actions`] (or
+    /// [`with_history_actions`](Self::with_history_actions)) set to `true`,
+    /// which costs up to two extra requests per call.
+    ///
+    /// Intraday equity requests with `include_prepost` also return pre- and
+    /// post-market bars. borsa's `Candle` has no session field, so callers
+    /// that need to tell them apart classify each bar's `ts` with
+    /// [`session::us_equity_session`].
     async fn history(
         &self,
         instrument: &Instrument,
@@ -198,28 +450,32 @@ impl HistoryProvider for AvConnector {
                     Self::normalize_error(e, &format!("history for {}", instrument.symbol()))
                 })
             }
-            AssetKind::Crypto => self
-                .history
-                .crypto(instrument.symbol_str(), &req)
-                .await
-                .map_err(|e| {
-                    Self::normalize_error(e, &format!("history for {}", instrument.symbol()))
-                }),
-            _ => self
-                .history
-                .equity(instrument.symbol_str(), &req)
-                .await
-                .map_err(|e| {
+            AssetKind::Crypto => {
+                let (base, market) = self.parse_crypto_pair(instrument.symbol_str())?;
+                self.history.crypto(base, market, &req).await.map_err(|e| {
                     Self::normalize_error(e, &format!("history for {}", instrument.symbol()))
-                }),
+                })
+            }
+            _ => {
+                let mut resp = self
+                    .history
+                    .equity(instrument.symbol_str(), &req)
+                    .await
+                    .map_err(|e| {
+                        Self::normalize_error(e, &format!("history for {}", instrument.symbol()))
+                    })?;
+                self.fill_actions(instrument, &req, &mut resp).await;
+                Ok(resp)
+            }
         }
     }
 
     fn supported_history_intervals(
         &self,
-        _kind: AssetKind,
+        kind: AssetKind,
     ) -> &'static [borsa_core::types::Interval] {
         use borsa_core::types::Interval as I;
+        // Every served kind has the same intervals; `M3` is resampled from the monthly series.
         const AV_INTERVALS: &[I] = &[
             I::I1m,
             I::I5m,
@@ -229,8 +485,12 @@ impl HistoryProvider for AvConnector {
             I::D1,
             I::W1,
             I::M1,
+            I::M3,
         ];
-        AV_INTERVALS
+        match kind {
+            AssetKind::Equity | AssetKind::Forex | AssetKind::Crypto => AV_INTERVALS,
+            _ => &[],
+        }
     }
 }
 
@@ -249,7 +509,6 @@ impl SearchProvider for AvConnector {
     }
 }
 
-/*
 #[async_trait]
 impl EarningsProvider for AvConnector {
     async fn earnings(&self, instrument: &Instrument) -> Result<borsa_core::Earnings, BorsaError> {
@@ -259,7 +518,64 @@ impl EarningsProvider for AvConnector {
             .map_err(|e| Self::normalize_error(e, &format!("earnings for {}", instrument.symbol())))
     }
 }
-*/
+
+#[async_trait]
+impl IncomeStatementProvider for AvConnector {
+    async fn income_statement(
+        &self,
+        instrument: &Instrument,
+        quarterly: bool,
+    ) -> Result<Vec<IncomeStatementRow>, BorsaError> {
+        self.fundamentals
+            .income_statement(instrument.symbol_str(), quarterly)
+            .await
+            .map_err(|e| {
+                Self::normalize_error(e, &format!("income statement for {}", instrument.symbol()))
+            })
+    }
+}
+
+#[async_trait]
+impl BalanceSheetProvider for AvConnector {
+    async fn balance_sheet(
+        &self,
+        instrument: &Instrument,
+        quarterly: bool,
+    ) -> Result<Vec<BalanceSheetRow>, BorsaError> {
+        self.fundamentals
+            .balance_sheet(instrument.symbol_str(), quarterly)
+            .await
+            .map_err(|e| {
+                Self::normalize_error(e, &format!("balance sheet for {}", instrument.symbol()))
+            })
+    }
+}
+
+#[async_trait]
+impl CashflowProvider for AvConnector {
+    async fn cashflow(
+        &self,
+        instrument: &Instrument,
+        quarterly: bool,
+    ) -> Result<Vec<CashflowRow>, BorsaError> {
+        self.fundamentals
+            .cashflow(instrument.symbol_str(), quarterly)
+            .await
+            .map_err(|e| {
+                Self::normalize_error(e, &format!("cash flow for {}", instrument.symbol()))
+            })
+    }
+}
+
+#[async_trait]
+impl ProfileProvider for AvConnector {
+    async fn profile(&self, instrument: &Instrument) -> Result<Profile, BorsaError> {
+        self.profile
+            .profile(instrument.symbol_str())
+            .await
+            .map_err(|e| Self::normalize_error(e, &format!("profile for {}", instrument.symbol())))
+    }
+}
 
 #[async_trait]
 impl BorsaConnector for AvConnector {
@@ -282,12 +598,25 @@ impl BorsaConnector for AvConnector {
     fn as_search_provider(&self) -> Option<&dyn borsa_core::connector::SearchProvider> {
         Some(self as &dyn SearchProvider)
     }
-    // Earnings provider unfortunately returns an error from the underlying crate, doesnt seem to be a bug in the connector implementation.
-    /*
     fn as_earnings_provider(&self) -> Option<&dyn borsa_core::connector::EarningsProvider> {
         Some(self as &dyn EarningsProvider)
     }
-    */
+    fn as_income_statement_provider(
+        &self,
+    ) -> Option<&dyn borsa_core::connector::IncomeStatementProvider> {
+        Some(self as &dyn IncomeStatementProvider)
+    }
+    fn as_balance_sheet_provider(
+        &self,
+    ) -> Option<&dyn borsa_core::connector::BalanceSheetProvider> {
+        Some(self as &dyn BalanceSheetProvider)
+    }
+    fn as_cashflow_provider(&self) -> Option<&dyn borsa_core::connector::CashflowProvider> {
+        Some(self as &dyn CashflowProvider)
+    }
+    fn as_profile_provider(&self) -> Option<&dyn borsa_core::connector::ProfileProvider> {
+        Some(self as &dyn ProfileProvider)
+    }
 
     fn supports_kind(&self, kind: AssetKind) -> bool {
         matches!(
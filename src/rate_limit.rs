@@ -0,0 +1,201 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use borsa_core::BorsaError;
+use tokio::time::Instant;
+
+use crate::error::AvError;
+
+const MINUTE: Duration = Duration::from_mins(1);
+const DAY: Duration = Duration::from_hours(24);
+
+/// Alpha Vantage key tier, which determines the request budgets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitTier {
+    /// Free key: 5 requests per minute and 25 per day.
+    Free,
+    /// Premium key with the given per-minute allowance and no daily cap.
+    Premium {
+        /// Requests per minute included in the plan (75, 150, 300, ...).
+        per_minute: u32,
+    },
+    /// No client-side limiting, e.g. when an outer layer already schedules
+    /// calls. The default, so upgraded keys are never throttled silently.
+    #[default]
+    Unlimited,
+}
+
+impl RateLimitTier {
+    /// Requests allowed per minute, or `None` when uncapped.
+    #[must_use]
+    pub const fn per_minute(self) -> Option<u32> {
+        match self {
+            Self::Free => Some(5),
+            Self::Premium { per_minute } => Some(per_minute),
+            Self::Unlimited => None,
+        }
+    }
+
+    /// Requests allowed per day, or `None` when uncapped.
+    #[must_use]
+    pub const fn per_day(self) -> Option<u32> {
+        match self {
+            Self::Free => Some(25),
+            Self::Premium { .. } | Self::Unlimited => None,
+        }
+    }
+}
+
+/// What to do when the per-minute budget is spent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WhenExhausted {
+    /// Wait for the next token before sending the request.
+    #[default]
+    Queue,
+    /// Return an error immediately.
+    FailFast,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    tokens: f64,
+    per_sec: f64,
+    last: Instant,
+}
+
+impl Bucket {
+    fn new(capacity: u32, period: Duration, now: Instant) -> Self {
+        let capacity = f64::from(capacity.max(1));
+        Self {
+            capacity,
+            tokens: capacity,
+            per_sec: capacity / period.as_secs_f64(),
+            last: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last).as_secs_f64();
+        self.tokens = elapsed
+            .mul_add(self.per_sec, self.tokens)
+            .min(self.capacity);
+        self.last = now;
+    }
+
+    /// Time until one whole token is available.
+    fn wait(&self) -> Duration {
+        if self.tokens >= 1.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64((1.0 - self.tokens) / self.per_sec)
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Buckets {
+    minute: Option<Bucket>,
+    day: Option<Bucket>,
+}
+
+/// Token-bucket scheduler for Alpha Vantage requests.
+///
+/// Clones share the same budget, so every clone of a `RealAdapter` draws from
+/// one pool. Spent per-minute tokens either queue the caller or fail fast
+/// according to [`WhenExhausted`]; a spent daily budget always fails fast,
+/// since waiting for it could take hours.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<Buckets>>,
+    when_exhausted: WhenExhausted,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(RateLimitTier::default(), WhenExhausted::default())
+    }
+}
+
+impl RateLimiter {
+    /// Build a scheduler with the budgets of `tier`.
+    #[must_use]
+    pub fn new(tier: RateLimitTier, when_exhausted: WhenExhausted) -> Self {
+        let now = Instant::now();
+        Self {
+            buckets: Arc::new(Mutex::new(Buckets {
+                minute: tier.per_minute().map(|n| Bucket::new(n, MINUTE, now)),
+                day: tier.per_day().map(|n| Bucket::new(n, DAY, now)),
+            })),
+            when_exhausted,
+        }
+    }
+
+    /// Take one request token, waiting for it when configured to queue.
+    ///
+    /// # Errors
+    /// Returns a connector error when the daily budget is spent, or when the
+    /// per-minute budget is spent and the limiter fails fast.
+    pub async fn acquire(&self) -> Result<(), BorsaError> {
+        loop {
+            let wait = self.try_take()?;
+            if wait.is_zero() {
+                return Ok(());
+            }
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Take a token if one is free; otherwise return how long to wait for it.
+    fn try_take(&self) -> Result<Duration, BorsaError> {
+        self.buckets
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .take(Instant::now(), self.when_exhausted)
+    }
+}
+
+impl Buckets {
+    fn take(
+        &mut self,
+        now: Instant,
+        when_exhausted: WhenExhausted,
+    ) -> Result<Duration, BorsaError> {
+        for bucket in [self.minute.as_mut(), self.day.as_mut()]
+            .into_iter()
+            .flatten()
+        {
+            bucket.refill(now);
+        }
+
+        if let Some(day) = &self.day
+            && !day.wait().is_zero()
+        {
+            return Err(exhausted("daily", day.wait()));
+        }
+        if let Some(minute) = &self.minute
+            && !minute.wait().is_zero()
+        {
+            return match when_exhausted {
+                WhenExhausted::Queue => Ok(minute.wait()),
+                WhenExhausted::FailFast => Err(exhausted("per-minute", minute.wait())),
+            };
+        }
+
+        for bucket in [self.minute.as_mut(), self.day.as_mut()]
+            .into_iter()
+            .flatten()
+        {
+            bucket.tokens -= 1.0;
+        }
+        Ok(Duration::ZERO)
+    }
+}
+
+fn exhausted(budget: &str, retry_after: Duration) -> BorsaError {
+    AvError::RateLimited {
+        retry_after: Some(Duration::from_secs_f64(retry_after.as_secs_f64().ceil())),
+        message: format!("{budget} request budget exhausted"),
+    }
+    .into()
+}
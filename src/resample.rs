@@ -0,0 +1,72 @@
+use borsa_core::{BorsaError, Candle, HistoryResponse};
+use chrono::Datelike;
+use chrono_tz::Tz;
+
+/// Aggregate monthly candles into calendar quarters.
+///
+/// Each quarter opens at its first month's open, closes at its last month's
+/// close and carries the last month's timestamp, matching Alpha Vantage's
+/// habit of labelling bars with their final trading day. Quarters are
+/// assigned in the response's timezone (UTC when absent), and a trailing
+/// quarter that is still in progress is kept as a partial bar. Actions are
+/// passed through unchanged.
+///
+/// # Errors
+/// Returns `BorsaError::Data` when volumes overflow or candles mix currencies.
+pub fn monthly_to_quarterly(monthly: HistoryResponse) -> Result<HistoryResponse, BorsaError> {
+    let tz = monthly
+        .meta
+        .as_ref()
+        .and_then(|m| m.timezone)
+        .unwrap_or(Tz::UTC);
+    let quarter_of = |c: &Candle| {
+        let local = c.ts.with_timezone(&tz);
+        (local.year(), local.month0() / 3)
+    };
+
+    let mut candles = monthly.candles;
+    candles.sort_by_key(|c| c.ts);
+
+    let mut quarters: Vec<Candle> = Vec::new();
+    let mut current: Option<(i32, u32)> = None;
+    for c in candles {
+        let key = quarter_of(&c);
+        match quarters.last_mut() {
+            Some(q) if current == Some(key) => merge_into(q, c)?,
+            _ => {
+                current = Some(key);
+                quarters.push(c);
+            }
+        }
+    }
+
+    Ok(HistoryResponse {
+        candles: quarters,
+        ..monthly
+    })
+}
+
+fn merge_into(q: &mut Candle, c: Candle) -> Result<(), BorsaError> {
+    if q.close.currency() != c.close.currency() {
+        return Err(BorsaError::Data(
+            "cannot resample candles priced in different currencies".into(),
+        ));
+    }
+    if c.high.amount() > q.high.amount() {
+        q.high = c.high;
+    }
+    if c.low.amount() < q.low.amount() {
+        q.low = c.low;
+    }
+    q.volume = match (q.volume, c.volume) {
+        (Some(a), Some(b)) => Some(
+            a.checked_add(b)
+                .ok_or_else(|| BorsaError::Data("quarterly volume overflow".into()))?,
+        ),
+        (a, b) => a.or(b),
+    };
+    q.ts = c.ts;
+    q.close = c.close;
+    q.close_unadj = c.close_unadj;
+    Ok(())
+}
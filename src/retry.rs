@@ -0,0 +1,101 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Retry policy for transient Alpha Vantage failures.
+///
+/// Only timeouts, HTTP 5xx responses and rate-limit responses are retried;
+/// not-found, invalid-argument and key errors surface immediately. Delays
+/// grow exponentially from `initial_backoff` up to `max_backoff`, and with
+/// jitter enabled each delay is drawn from its upper half. A rate-limit
+/// response waits for its retry hint, capped at `max_backoff`; a spent daily
+/// budget is surfaced without retrying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts, backing off from 500ms up to 30s with jitter.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that makes a single attempt.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+            jitter: false,
+        }
+    }
+
+    /// Total attempts per call, including the first; values below 1 count as 1.
+    #[must_use]
+    pub const fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = if attempts == 0 { 1 } else { attempts };
+        self
+    }
+
+    /// Delay before the first retry and the cap for later ones.
+    #[must_use]
+    pub const fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Randomise each delay within its upper half.
+    #[must_use]
+    pub const fn jitter(mut self, enabled: bool) -> Self {
+        self.jitter = enabled;
+        self
+    }
+
+    /// Total attempts per call.
+    #[must_use]
+    pub const fn attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Delay before retry number `retry` (0 for the first retry).
+    #[must_use]
+    pub fn delay(&self, retry: u32) -> Duration {
+        let base = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_backoff);
+        if !self.jitter || base.is_zero() {
+            return base;
+        }
+        let half = base / 2;
+        let nanos = u64::try_from(half.as_nanos()).unwrap_or(u64::MAX);
+        let random = RandomState::new().build_hasher().finish();
+        half + Duration::from_nanos(random % nanos.saturating_add(1))
+    }
+
+    /// Delay before the next attempt after `failed` attempts, or `None` to give up.
+    ///
+    /// `hint` is the server's retry-after for rate-limit responses.
+    pub(crate) fn next_delay(&self, failed: u32, hint: Option<Duration>) -> Option<Duration> {
+        if failed >= self.max_attempts {
+            return None;
+        }
+        Some(hint.map_or_else(
+            || self.delay(failed.saturating_sub(1)),
+            |h| h.min(self.max_backoff),
+        ))
+    }
+}
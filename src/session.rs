@@ -0,0 +1,57 @@
+use borsa_core::MarketState;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Utc, Weekday};
+use chrono_tz::{Tz, US::Eastern};
+
+/// Trading session boundaries for US equities, in exchange-local time.
+///
+/// Alpha Vantage's extended-hours intraday data covers 04:00–20:00 US/Eastern.
+/// Exchange holidays are not modelled; they classify like ordinary weekdays.
+const PRE_OPEN: (u32, u32) = (4, 0);
+const REGULAR_OPEN: (u32, u32) = (9, 30);
+const REGULAR_CLOSE: (u32, u32) = (16, 0);
+const POST_CLOSE: (u32, u32) = (20, 0);
+
+/// US equity exchange timezone.
+pub const US_EQUITY_TZ: Tz = Eastern;
+
+fn hm(t: (u32, u32)) -> NaiveTime {
+    NaiveTime::from_hms_opt(t.0, t.1, 0).unwrap_or(NaiveTime::MIN)
+}
+
+/// Session a US equity bar starting at `ts` belongs to.
+///
+/// Returns `Pre`, `Regular` or `Post` during the respective windows and
+/// `Closed` outside them or on weekends. History candles carry no session, so
+/// this is how callers mark extended-hours bars.
+#[must_use]
+pub fn us_equity_session(ts: DateTime<Utc>) -> MarketState {
+    let local = ts.with_timezone(&US_EQUITY_TZ);
+    if matches!(local.weekday(), Weekday::Sat | Weekday::Sun) {
+        return MarketState::Closed;
+    }
+    let t = local.time();
+    if t < hm(PRE_OPEN) || t >= hm(POST_CLOSE) {
+        MarketState::Closed
+    } else if t < hm(REGULAR_OPEN) {
+        MarketState::Pre
+    } else if t < hm(REGULAR_CLOSE) {
+        MarketState::Regular
+    } else {
+        MarketState::Post
+    }
+}
+
+/// Whether a US equity quote whose latest trading day is `latest_trading_day`
+/// lags the exchange at `now`.
+///
+/// A quote is stale once the regular session of a newer day has opened
+/// without reaching it, as end-of-day `GLOBAL_QUOTE` data does all session
+/// without a realtime entitlement. The market state itself always comes from
+/// the exchange clock ([`us_equity_session`]).
+#[must_use]
+pub fn us_equity_quote_is_stale(latest_trading_day: NaiveDate, now: DateTime<Utc>) -> bool {
+    matches!(
+        us_equity_session(now),
+        MarketState::Regular | MarketState::Post
+    ) && latest_trading_day < now.with_timezone(&US_EQUITY_TZ).date_naive()
+}
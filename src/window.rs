@@ -0,0 +1,167 @@
+use borsa_core::{BorsaError, HistoryRequest, HistoryResponse, Interval, Range};
+use chrono::{DateTime, Datelike, Months, TimeDelta, TimeZone, Utc};
+
+use crate::session::US_EQUITY_TZ;
+
+/// Number of points Alpha Vantage returns for `outputsize=compact`.
+const COMPACT_POINTS: i64 = 100;
+/// Days of intraday history `TIME_SERIES_INTRADAY` returns without a `month` parameter.
+const INTRADAY_TRAILING_DAYS: i64 = 30;
+/// First year Alpha Vantage serves month-sliced intraday data for.
+const INTRADAY_FIRST_YEAR: i32 = 2000;
+/// Default cap on the month slices one intraday request may fan out to.
+///
+/// A two-year range touches 25 calendar months. Raise it with
+/// [`AvConnectorBuilder::max_intraday_months`](crate::AvConnectorBuilder::max_intraday_months).
+pub const DEFAULT_MAX_INTRADAY_MONTHS: usize = 25;
+
+/// The slice of time a `HistoryRequest` asks for.
+///
+/// Range presets are anchored at the most recent candle rather than the wall
+/// clock, so a one-day range fetched over a weekend still returns Friday's bars.
+/// Explicit periods are the half-open interval `[start, end)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryWindow {
+    /// Lookback preset ending at the latest candle.
+    Range(Range),
+    /// Explicit `[start, end)` period.
+    Period {
+        /// Inclusive start.
+        start: DateTime<Utc>,
+        /// Exclusive end.
+        end: DateTime<Utc>,
+    },
+}
+
+impl HistoryWindow {
+    /// Resolve the window described by `req`.
+    #[must_use]
+    pub const fn from_request(req: &HistoryRequest) -> Self {
+        match (req.range(), req.period()) {
+            (_, Some((start, end))) => Self::Period { start, end },
+            (Some(range), None) => Self::Range(range),
+            (None, None) => Self::Range(Range::Max),
+        }
+    }
+
+    /// Earliest timestamp covered by the window when it ends at `anchor`, or `None` when unbounded.
+    #[must_use]
+    pub fn start(&self, anchor: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let range = match self {
+            Self::Period { start, .. } => return Some(*start),
+            Self::Range(range) => *range,
+        };
+        let days = |n| anchor.checked_sub_signed(TimeDelta::days(n));
+        let months = |n| anchor.checked_sub_months(Months::new(n));
+        match range {
+            Range::D1 => days(1),
+            Range::D5 => days(5),
+            Range::M1 => months(1),
+            Range::M3 => months(3),
+            Range::M6 => months(6),
+            Range::Y1 => months(12),
+            Range::Y2 => months(24),
+            Range::Y5 => months(60),
+            Range::Y10 => months(120),
+            Range::Ytd => Utc.with_ymd_and_hms(anchor.year(), 1, 1, 0, 0, 0).single(),
+            Range::Max => None,
+        }
+    }
+
+    /// Whether `ts` falls inside the window ending at `anchor`.
+    #[must_use]
+    pub fn contains(&self, ts: DateTime<Utc>, anchor: DateTime<Utc>) -> bool {
+        match self {
+            Self::Period { start, end } => *start <= ts && ts < *end,
+            Self::Range(Range::Ytd) => self.start(anchor).is_none_or(|s| ts >= s),
+            Self::Range(_) => self.start(anchor).is_none_or(|s| ts > s),
+        }
+    }
+
+    /// Whether the latest 100 points at `interval` are guaranteed to cover the window.
+    ///
+    /// Only intraday and daily series accept `outputsize`; 100 bars span at
+    /// least 100 intervals of wall-clock time, so the check is conservative.
+    #[must_use]
+    pub fn fits_compact(&self, interval: Interval, now: DateTime<Utc>) -> bool {
+        let span = match (interval.minutes(), interval) {
+            (Some(m), _) => TimeDelta::minutes(m * COMPACT_POINTS),
+            (None, Interval::D1) => TimeDelta::days(COMPACT_POINTS),
+            _ => return false,
+        };
+        self.start(now)
+            .is_some_and(|start| now.checked_sub_signed(span).is_some_and(|c| start >= c))
+    }
+
+    /// `YYYY-MM` slices to request when the window reaches past the trailing intraday window.
+    ///
+    /// Months are calendar months in US/Eastern, the timezone Alpha Vantage
+    /// slices by. Returns an empty list when a single call without `month`
+    /// covers the window, including unbounded `Max` ranges.
+    ///
+    /// Each slice is one request, so `max_months` bounds the quota a single
+    /// history call can spend; the connector passes its configured cap
+    /// ([`DEFAULT_MAX_INTRADAY_MONTHS`] unless raised on the builder), and the
+    /// rate limiter paces the slices it allows.
+    ///
+    /// # Errors
+    /// Returns `BorsaError::InvalidArg` when the window spans more than
+    /// `max_months` months, e.g. `Y5` and `Y10` ranges under the default cap.
+    pub fn intraday_months(
+        &self,
+        now: DateTime<Utc>,
+        max_months: usize,
+    ) -> Result<Vec<String>, BorsaError> {
+        let Some(start) = self.start(now) else {
+            return Ok(Vec::new());
+        };
+        if now
+            .checked_sub_signed(TimeDelta::days(INTRADAY_TRAILING_DAYS))
+            .is_some_and(|c| start >= c)
+        {
+            return Ok(Vec::new());
+        }
+        let last = match self {
+            Self::Period { end, .. } => (*end - TimeDelta::seconds(1)).min(now),
+            Self::Range(_) => now,
+        };
+        let start = start.with_timezone(&US_EQUITY_TZ);
+        let last = last.with_timezone(&US_EQUITY_TZ);
+        let mut ym = if start.year() < INTRADAY_FIRST_YEAR {
+            (INTRADAY_FIRST_YEAR, 1)
+        } else {
+            (start.year(), start.month())
+        };
+        let mut months = Vec::new();
+        while ym <= (last.year(), last.month()) {
+            if months.len() == max_months {
+                return Err(BorsaError::InvalidArg(format!(
+                    "intraday history spans more than {max_months} months; request a shorter window or raise max_intraday_months"
+                )));
+            }
+            months.push(format!("{:04}-{:02}", ym.0, ym.1));
+            ym = if ym.1 == 12 {
+                (ym.0 + 1, 1)
+            } else {
+                (ym.0, ym.1 + 1)
+            };
+        }
+        Ok(months)
+    }
+
+    /// Drop candles and actions outside the window, anchoring range presets at the latest candle.
+    pub fn trim(&self, resp: &mut HistoryResponse) {
+        let Some(anchor) = resp.candles.iter().map(|c| c.ts).max() else {
+            return;
+        };
+        resp.candles.retain(|c| self.contains(c.ts, anchor));
+        resp.actions.retain(|a| {
+            let ts = match a {
+                borsa_core::Action::Dividend { ts, .. }
+                | borsa_core::Action::Split { ts, .. }
+                | borsa_core::Action::CapitalGain { ts, .. } => *ts,
+            };
+            self.contains(ts, anchor)
+        });
+    }
+}
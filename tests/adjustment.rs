@@ -0,0 +1,94 @@
+use borsa_alphavantage::api::time_series::TimeSeriesResponse;
+use borsa_alphavantage::window::HistoryWindow;
+use borsa_core::{Candle, Currency, HistoryResponse, IsoCurrency, Range};
+use chrono::{TimeZone, Utc};
+
+const USD: Currency = Currency::Iso(IsoCurrency::USD);
+
+fn map(json: &str) -> HistoryResponse {
+    let ts: TimeSeriesResponse = serde_json::from_str(json).expect("valid payload");
+    ts.to_history(&USD, &HistoryWindow::Range(Range::Max))
+        .expect("maps")
+}
+
+fn assert_consistent(c: &Candle) {
+    let (o, h, l, cl) = (
+        c.open.amount(),
+        c.high.amount(),
+        c.low.amount(),
+        c.close.amount(),
+    );
+    assert!(l <= o && o <= h, "open outside range at {}: {c:?}", c.ts);
+    assert!(l <= cl && cl <= h, "close outside range at {}: {c:?}", c.ts);
+}
+
+#[test]
+fn bars_stay_consistent_across_a_split() {
+    let resp = map(include_str!("fixtures/daily_adjusted_aapl.json"));
+    assert_eq!(resp.candles.len(), 4);
+    resp.candles.iter().for_each(assert_consistent);
+}
+
+#[test]
+fn pre_split_bars_are_scaled_by_the_adjustment_factor() {
+    let resp = map(include_str!("fixtures/daily_adjusted_aapl.json"));
+    let pre = resp
+        .candles
+        .iter()
+        .find(|c| c.ts == Utc.with_ymd_and_hms(2020, 8, 28, 4, 0, 0).unwrap())
+        .unwrap();
+    // adjusted/raw close = 124.8075 / 499.23 = 0.25
+    assert_eq!(pre.open.amount().to_string(), "126.01");
+    assert_eq!(pre.high.amount().to_string(), "126.44");
+    assert_eq!(pre.low.amount().to_string(), "124.58");
+    assert_eq!(pre.close.amount().to_string(), "124.81");
+    // Volume is restated in post-split shares.
+    assert_eq!(pre.volume, Some(187_629_916));
+
+    let post = resp
+        .candles
+        .iter()
+        .find(|c| c.ts == Utc.with_ymd_and_hms(2020, 8, 31, 4, 0, 0).unwrap())
+        .unwrap();
+    assert_eq!(post.open.amount().to_string(), "127.58");
+    assert_eq!(post.volume, Some(225_702_700));
+}
+
+#[test]
+fn dividend_adjustment_scales_the_whole_bar() {
+    let json = r#"{
+        "Meta Data": { "5. Time Zone": "US/Eastern" },
+        "Time Series (Daily)": {
+            "2023-11-09": { "1. open": "100.00", "2. high": "104.00", "3. low": "96.00",
+                            "4. close": "100.00", "5. adjusted close": "98.00",
+                            "6. volume": "1000", "7. dividend amount": "0.0000",
+                            "8. split coefficient": "1.0" },
+            "2023-11-10": { "1. open": "99.00", "2. high": "101.00", "3. low": "97.50",
+                            "4. close": "97.60", "5. adjusted close": "97.60",
+                            "6. volume": "1200", "7. dividend amount": "2.0000",
+                            "8. split coefficient": "1.0" }
+        }
+    }"#;
+    let resp = map(json);
+    resp.candles.iter().for_each(assert_consistent);
+    let first = &resp.candles[0];
+    assert_eq!(first.open.amount().to_string(), "98");
+    assert_eq!(first.high.amount().to_string(), "101.92");
+    assert_eq!(first.low.amount().to_string(), "94.08");
+}
+
+#[test]
+fn unadjusted_bars_are_left_untouched() {
+    let json = r#"{
+        "Meta Data": { "6. Time Zone": "US/Eastern" },
+        "Time Series (5min)": {
+            "2024-01-02 10:00:00": { "1. open": "10.00", "2. high": "11.00", "3. low": "9.00",
+                                     "4. close": "10.50", "5. volume": "300" }
+        }
+    }"#;
+    let resp = map(json);
+    let c = &resp.candles[0];
+    assert_eq!(c.open.amount().to_string(), "10");
+    assert_eq!(c.high.amount().to_string(), "11");
+    assert_eq!(c.volume, Some(300));
+}
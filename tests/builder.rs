@@ -0,0 +1,415 @@
+mod common;
+
+use std::time::Duration;
+
+use borsa_alphavantage::rate_limit::{RateLimitTier, WhenExhausted};
+use borsa_alphavantage::retry::RetryPolicy;
+use borsa_alphavantage::{AvConnector, Entitlement, ProxyMode};
+use borsa_core::{
+    AssetKind, BorsaError, HistoryRequest, Instrument, Interval, Range,
+    connector::{EarningsProvider, HistoryProvider, QuoteProvider},
+};
+use common::{Reply, StandIn, query_param};
+
+const EARNINGS: &str = include_str!("fixtures/earnings.json");
+
+fn instrument(symbol: &str, kind: AssetKind) -> Instrument {
+    Instrument::from_symbol(symbol, kind).expect("valid test instrument")
+}
+
+#[tokio::test]
+async fn timeout_applies_per_attempt() {
+    let server = StandIn::start(vec![(
+        "EARNINGS",
+        vec![Reply::json(EARNINGS).delayed(Duration::from_secs(2))],
+    )])
+    .await;
+    let av = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .timeout(Duration::from_millis(100))
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+
+    let err = av
+        .earnings(&instrument("IBM", AssetKind::Equity))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, BorsaError::ProviderTimeout { .. }), "{err:?}");
+}
+
+#[tokio::test]
+async fn entitlement_is_sent_only_where_accepted() {
+    let server = StandIn::start(vec![
+        (
+            "TIME_SERIES_INTRADAY",
+            vec![Reply::json(include_str!("fixtures/intraday_5min.json"))],
+        ),
+        ("EARNINGS", vec![Reply::json(EARNINGS)]),
+    ])
+    .await;
+    let av = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .entitlement(Entitlement::Delayed)
+        .build()
+        .unwrap();
+    let ibm = instrument("IBM", AssetKind::Equity);
+
+    let req = HistoryRequest::try_from_range(Range::D1, Interval::I15m).unwrap();
+    let _ = av.history(&ibm, req).await;
+    av.earnings(&ibm).await.unwrap();
+
+    let targets = server.targets();
+    let intraday = targets
+        .iter()
+        .find(|t| t.contains("TIME_SERIES_INTRADAY"))
+        .unwrap();
+    let earnings = targets.iter().find(|t| t.contains("EARNINGS")).unwrap();
+    assert_eq!(
+        query_param(intraday, "entitlement").as_deref(),
+        Some("delayed")
+    );
+    assert_eq!(query_param(earnings, "entitlement"), None);
+}
+
+#[tokio::test]
+async fn entitlement_is_sent_on_global_quote() {
+    let server = StandIn::start(vec![(
+        "GLOBAL_QUOTE",
+        vec![Reply::json(
+            r#"{"Global Quote": {"01. symbol": "IBM", "02. open": "1", "03. high": "1",
+                "04. low": "1", "05. price": "1", "06. volume": "1",
+                "07. latest trading day": "2024-05-03", "08. previous close": "1",
+                "09. change": "0", "10. change percent": "0%"}}"#,
+        )],
+    )])
+    .await;
+    let av = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .entitlement(Entitlement::Realtime)
+        .build()
+        .unwrap();
+
+    av.quote(&instrument("IBM", AssetKind::Equity))
+        .await
+        .unwrap();
+    assert_eq!(
+        query_param(&server.targets()[0], "entitlement").as_deref(),
+        Some("realtime")
+    );
+}
+
+#[tokio::test]
+async fn bare_crypto_symbols_use_the_default_market() {
+    let server = StandIn::start(vec![(
+        "CRYPTO_INTRADAY",
+        vec![Reply::json(include_str!(
+            "fixtures/crypto_intraday_5min.json"
+        ))],
+    )])
+    .await;
+    let av = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .crypto_market("eur")
+        .build()
+        .unwrap();
+
+    let req = HistoryRequest::try_from_range(Range::D1, Interval::I5m).unwrap();
+    let _ = av.history(&instrument("ETH", AssetKind::Crypto), req).await;
+
+    let target = &server.targets()[0];
+    assert_eq!(query_param(target, "symbol").as_deref(), Some("ETH"));
+    assert_eq!(query_param(target, "market").as_deref(), Some("EUR"));
+}
+
+#[tokio::test]
+async fn fail_fast_tier_stops_before_the_server() {
+    let server = StandIn::start(vec![("EARNINGS", vec![Reply::json(EARNINGS)])]).await;
+    let av = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .rate_limit(RateLimitTier::Premium { per_minute: 1 })
+        .when_rate_limited(WhenExhausted::FailFast)
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+    let ibm = instrument("IBM", AssetKind::Equity);
+
+    av.earnings(&ibm).await.unwrap();
+    let err = av.earnings(&ibm).await.unwrap_err();
+    assert!(matches!(err, BorsaError::Connector { .. }), "{err:?}");
+    assert_eq!(server.targets().len(), 1);
+}
+
+#[tokio::test]
+async fn requests_are_not_throttled_without_a_tier() {
+    let server = StandIn::start(vec![("EARNINGS", vec![Reply::json(EARNINGS)])]).await;
+    let av = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .build()
+        .unwrap();
+    let ibm = instrument("IBM", AssetKind::Equity);
+
+    // Six calls would queue for over ten seconds on the free tier.
+    tokio::time::timeout(Duration::from_secs(5), async {
+        for _ in 0..6 {
+            av.earnings(&ibm).await.unwrap();
+        }
+    })
+    .await
+    .expect("no client-side throttle by default");
+    assert_eq!(server.targets().len(), 6);
+}
+
+#[tokio::test]
+async fn retry_policy_none_makes_one_attempt() {
+    let server = StandIn::start(vec![(
+        "EARNINGS",
+        vec![Reply::status(503), Reply::json(EARNINGS)],
+    )])
+    .await;
+    let av = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap();
+
+    assert!(
+        av.earnings(&instrument("IBM", AssetKind::Equity))
+            .await
+            .is_err()
+    );
+    assert_eq!(server.targets().len(), 1);
+}
+
+#[tokio::test]
+async fn earnings_join_the_income_statement_when_enabled() {
+    let server = StandIn::start(vec![
+        ("EARNINGS", vec![Reply::json(EARNINGS)]),
+        (
+            "INCOME_STATEMENT",
+            vec![Reply::json(include_str!(
+                "fixtures/income_statement_ibm.json"
+            ))],
+        ),
+    ])
+    .await;
+    let ibm = instrument("IBM", AssetKind::Equity);
+
+    let plain = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .build()
+        .unwrap();
+    let earnings = plain.earnings(&ibm).await.unwrap();
+    assert!(earnings.yearly[0].revenue.is_none());
+    assert_eq!(server.targets().len(), 1);
+
+    let joined = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .earnings_income_statement(true)
+        .build()
+        .unwrap();
+    let earnings = joined.earnings(&ibm).await.unwrap();
+    assert_eq!(
+        earnings.yearly[0]
+            .revenue
+            .as_ref()
+            .unwrap()
+            .amount()
+            .to_string(),
+        "61860000000"
+    );
+    let functions: Vec<String> = server
+        .targets()
+        .iter()
+        .filter_map(|t| query_param(t, "function"))
+        .collect();
+    assert_eq!(functions, ["EARNINGS", "EARNINGS", "INCOME_STATEMENT"]);
+}
+
+#[tokio::test]
+async fn failed_income_statement_keeps_the_earnings() {
+    let server = StandIn::start(vec![
+        ("EARNINGS", vec![Reply::json(EARNINGS)]),
+        (
+            "INCOME_STATEMENT",
+            vec![Reply::json(
+                r#"{"Information": "Thank you for using Alpha Vantage! This is a premium endpoint."}"#,
+            )],
+        ),
+    ])
+    .await;
+    let av = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .earnings_income_statement(true)
+        .build()
+        .unwrap();
+
+    let earnings = av
+        .earnings(&instrument("IBM", AssetKind::Equity))
+        .await
+        .unwrap();
+    assert!(!earnings.quarterly_eps.is_empty());
+    assert!(earnings.yearly[0].revenue.is_none());
+    assert_eq!(server.targets().len(), 2);
+}
+
+#[tokio::test]
+async fn user_agent_is_sent() {
+    let server = StandIn::start(vec![("EARNINGS", vec![Reply::json(EARNINGS)])]).await;
+    let av = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .user_agent("borsa-test/1.0")
+        .build()
+        .unwrap();
+
+    av.earnings(&instrument("IBM", AssetKind::Equity))
+        .await
+        .unwrap();
+    let head = server.requests()[0].to_ascii_lowercase();
+    assert!(head.contains("user-agent: borsa-test/1.0"), "{head}");
+}
+
+#[test]
+fn user_agent_is_validated() {
+    assert!(matches!(
+        AvConnector::builder()
+            .api_key("K")
+            .user_agent("bad\nagent")
+            .build(),
+        Err(BorsaError::InvalidArg(_))
+    ));
+    assert!(matches!(
+        AvConnector::builder()
+            .api_key("K")
+            .http_client(reqwest::Client::new())
+            .user_agent("borsa-test/1.0")
+            .build(),
+        Err(BorsaError::InvalidArg(_))
+    ));
+}
+
+#[test]
+fn proxy_settings_are_validated() {
+    assert!(matches!(
+        AvConnector::builder()
+            .api_key("K")
+            .proxy(ProxyMode::Url("::not a proxy::".into()))
+            .build(),
+        Err(BorsaError::InvalidArg(_))
+    ));
+    assert!(matches!(
+        AvConnector::builder()
+            .api_key("K")
+            .http_client(reqwest::Client::new())
+            .proxy(ProxyMode::Disabled)
+            .build(),
+        Err(BorsaError::InvalidArg(_))
+    ));
+    assert!(
+        AvConnector::builder()
+            .rapidapi_key("K")
+            .proxy(ProxyMode::Url("http://127.0.0.1:3128".into()))
+            .build()
+            .is_ok()
+    );
+}
+
+#[test]
+fn empty_crypto_market_is_rejected() {
+    assert!(matches!(
+        AvConnector::builder()
+            .api_key("K")
+            .crypto_market("  ")
+            .build(),
+        Err(BorsaError::InvalidArg(_))
+    ));
+}
+
+#[test]
+fn crypto_market_without_minor_units_is_rejected() {
+    let Err(err) = AvConnector::builder()
+        .api_key("K")
+        .crypto_market("busd")
+        .build()
+    else {
+        panic!("BUSD has no minor units");
+    };
+    assert!(
+        matches!(&err, BorsaError::InvalidArg(m) if m.contains("BUSD")),
+        "{err:?}"
+    );
+    for market in ["btc", "usdt"] {
+        assert!(
+            AvConnector::builder()
+                .api_key("K")
+                .crypto_market(market)
+                .build()
+                .is_ok(),
+            "{market}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn explicit_crypto_market_without_minor_units_fails_before_the_server() {
+    let server = StandIn::start(vec![]).await;
+    let av = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .build()
+        .unwrap();
+
+    let err = av
+        .quote(&instrument("BTC/BUSD", AssetKind::Crypto))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, BorsaError::InvalidArg(_)), "{err:?}");
+    assert!(server.targets().is_empty());
+}
+
+#[tokio::test]
+async fn intraday_month_cap_is_configurable() {
+    let server = StandIn::start(vec![(
+        "TIME_SERIES_INTRADAY",
+        vec![Reply::json(include_str!("fixtures/intraday_5min.json"))],
+    )])
+    .await;
+    let req = HistoryRequest::try_from_range(Range::Y5, Interval::I1h).unwrap();
+
+    let capped = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .build()
+        .unwrap();
+    let err = capped
+        .history(&instrument("IBM", AssetKind::Equity), req.clone())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, BorsaError::InvalidArg(_)), "{err:?}");
+    assert!(server.targets().is_empty());
+
+    let raised = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .max_intraday_months(70)
+        .build()
+        .unwrap();
+    raised
+        .history(&instrument("IBM", AssetKind::Equity), req)
+        .await
+        .unwrap();
+    let targets = server.targets();
+    assert!(targets.len() > 60, "{}", targets.len());
+    assert!(targets.iter().all(|t| query_param(t, "month").is_some()));
+}
@@ -0,0 +1,77 @@
+use borsa_alphavantage::api::bulk_quotes::{BulkQuote, BulkQuotesResponse};
+use borsa_core::{BorsaError, MarketState};
+use chrono::{TimeZone, Utc};
+
+fn load() -> BulkQuotesResponse {
+    serde_json::from_str(include_str!("fixtures/realtime_bulk_quotes.json")).expect("valid fixture")
+}
+
+fn decoded() -> Vec<BulkQuote> {
+    load()
+        .rows()
+        .into_iter()
+        .map(|(_, row)| row.expect("valid row"))
+        .collect()
+}
+
+#[test]
+fn broken_rows_fail_on_their_own() {
+    let resp: BulkQuotesResponse =
+        serde_json::from_str(include_str!("fixtures/realtime_bulk_quotes_broken.json"))
+            .expect("valid envelope");
+    let rows = resp.rows();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[0].0, "MSFT");
+    assert!(rows[0].1.is_ok());
+    assert_eq!(rows[1].0, "IBM");
+    assert!(
+        matches!(rows[1].1, Err(BorsaError::Data(_))),
+        "{:?}",
+        rows[1].1
+    );
+    assert_eq!(rows[2].0, "");
+    assert!(rows[2].1.is_err(), "a row without a symbol cannot decode");
+}
+
+#[test]
+fn bulk_rows_decode_every_field() {
+    let rows = decoded();
+    assert_eq!(rows.len(), 2);
+    let msft = &rows[0];
+    assert_eq!(msft.symbol, "MSFT");
+    assert_eq!(msft.open, Some(402.28));
+    assert_eq!(msft.high, Some(407.15));
+    assert_eq!(msft.low, Some(401.86));
+    assert_eq!(msft.close, Some(406.66));
+    assert_eq!(msft.volume, Some(17_446_720.0));
+    assert_eq!(msft.previous_close, Some(397.84));
+    assert_eq!(msft.change_percent, Some(2.217));
+    assert_eq!(msft.extended_hours_quote, Some(406.35));
+    // Eastern daylight time is UTC-4.
+    assert_eq!(
+        msft.time().map(|t| t.timestamp()),
+        Some(
+            Utc.with_ymd_and_hms(2024, 5, 3, 19, 59, 59)
+                .unwrap()
+                .timestamp()
+        )
+    );
+    assert_eq!(rows[1].extended_hours_quote, None);
+}
+
+#[test]
+fn bulk_rows_map_to_usd_quotes_with_market_state() {
+    let rows = decoded();
+    let after_close = Utc.with_ymd_and_hms(2024, 5, 3, 21, 0, 0).unwrap();
+    let quote = rows[1].to_quote_at(after_close).unwrap();
+    assert_eq!(quote.symbol.as_str(), "AAPL");
+    assert_eq!(quote.price.unwrap().amount().to_string(), "183.38");
+    assert_eq!(quote.previous_close.unwrap().amount().to_string(), "173.03");
+    assert_eq!(quote.market_state, Some(MarketState::Post));
+}
+
+#[test]
+fn empty_bulk_response_has_no_rows() {
+    let resp: BulkQuotesResponse = serde_json::from_str("{}").unwrap();
+    assert!(resp.data.is_empty());
+}
@@ -0,0 +1,65 @@
+//! Injected adapters and bars for the corporate-action tests.
+
+use std::sync::Arc;
+
+use borsa_alphavantage::adapter::{self, AvCorporateActions, AvHistory};
+use borsa_core::{
+    Action, AssetKind, Candle, Currency, HistoryResponse, Instrument, IsoCurrency, Money,
+};
+use chrono::{DateTime, TimeZone, Utc};
+
+/// History and corporate-action adapters for `AvConnector::from_adapter`.
+pub struct A {
+    pub h: Arc<dyn AvHistory>,
+    pub c: Arc<dyn AvCorporateActions>,
+}
+
+impl adapter::CloneArcAdapters for A {
+    fn clone_arc_history(&self) -> Arc<dyn AvHistory> {
+        self.h.clone()
+    }
+    fn clone_arc_actions(&self) -> Arc<dyn AvCorporateActions> {
+        self.c.clone()
+    }
+}
+
+pub fn utc(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+}
+
+pub fn usd(v: &str) -> Money {
+    Money::from_canonical_str(v, Currency::Iso(IsoCurrency::USD)).unwrap()
+}
+
+pub fn candle(ts: DateTime<Utc>) -> Candle {
+    Candle {
+        ts,
+        open: usd("120"),
+        high: usd("125"),
+        low: usd("118"),
+        close: usd("121"),
+        close_unadj: None,
+        volume: Some(1_000),
+    }
+}
+
+/// Equity bars at `stamps` carrying the given series actions; pair history is unreachable.
+pub fn bars(stamps: &[DateTime<Utc>], actions: Vec<Action>) -> Arc<dyn AvHistory> {
+    let candles: Vec<Candle> = stamps.iter().copied().map(candle).collect();
+    <dyn AvHistory>::from_fns(
+        move |_s, _r| {
+            Ok(HistoryResponse {
+                candles: candles.clone(),
+                actions: actions.clone(),
+                adjusted: false,
+                meta: None,
+            })
+        },
+        |_b, _q, _r| unreachable!(),
+        |_b, _m, _r| unreachable!(),
+    )
+}
+
+pub fn equity(symbol: &str) -> Instrument {
+    Instrument::from_symbol(symbol, AssetKind::Equity).expect("valid test instrument")
+}
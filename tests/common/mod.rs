@@ -0,0 +1,171 @@
+//! Local HTTP stand-in for Alpha Vantage, serving canned bodies per `function`,
+//! and shared injected adapters.
+
+#![allow(dead_code)]
+
+#[cfg(feature = "test-adapters")]
+mod adapters;
+#[cfg(feature = "test-adapters")]
+#[allow(unused_imports)]
+pub use adapters::*;
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// One canned response.
+#[derive(Clone)]
+pub struct Reply {
+    pub status: u16,
+    pub body: String,
+    pub delay: Duration,
+    pub headers: Vec<(String, String)>,
+}
+
+impl Reply {
+    pub fn json(body: impl Into<String>) -> Self {
+        Self {
+            status: 200,
+            body: body.into(),
+            delay: Duration::ZERO,
+            headers: Vec::new(),
+        }
+    }
+
+    pub const fn status(status: u16) -> Self {
+        Self {
+            status,
+            body: String::new(),
+            delay: Duration::ZERO,
+            headers: Vec::new(),
+        }
+    }
+
+    /// Send an extra response header.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Hold the response back for `delay` before sending it.
+    pub const fn delayed(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+/// Running stand-in server.
+pub struct StandIn {
+    pub base_url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl StandIn {
+    /// Serve `routes`, keyed by the `function` query parameter. Each route
+    /// replays its replies in order and then repeats the last one.
+    pub async fn start(routes: Vec<(&str, Vec<Reply>)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let addr = listener.local_addr().expect("addr");
+        let routes: HashMap<String, VecDeque<Reply>> = routes
+            .into_iter()
+            .map(|(f, r)| (f.to_string(), r.into_iter().collect()))
+            .collect();
+        let routes = Arc::new(Mutex::new(routes));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let log = Arc::clone(&requests);
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut sock, _)) = listener.accept().await else {
+                    return;
+                };
+                let routes = Arc::clone(&routes);
+                let log = Arc::clone(&log);
+                tokio::spawn(async move {
+                    let mut buf = Vec::new();
+                    let mut chunk = [0u8; 4096];
+                    while !buf.windows(4).any(|w| w == b"\r\n\r\n") {
+                        match sock.read(&mut chunk).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => buf.extend_from_slice(&chunk[..n]),
+                        }
+                    }
+                    let head = String::from_utf8_lossy(&buf).to_string();
+                    let target = head
+                        .lines()
+                        .next()
+                        .and_then(|l| l.split_whitespace().nth(1))
+                        .unwrap_or_default()
+                        .to_string();
+                    log.lock().unwrap().push(head);
+                    let function = query_param(&target, "function").unwrap_or_default();
+                    let reply = {
+                        let mut routes = routes.lock().unwrap();
+                        routes.get_mut(&function).map_or_else(
+                            || Reply::status(404),
+                            |q| {
+                                if q.len() > 1 {
+                                    q.pop_front().unwrap()
+                                } else {
+                                    q.front().cloned().unwrap()
+                                }
+                            },
+                        )
+                    };
+                    tokio::time::sleep(reply.delay).await;
+                    let mut extra = String::new();
+                    for (name, value) in &reply.headers {
+                        extra.push_str(name);
+                        extra.push_str(": ");
+                        extra.push_str(value);
+                        extra.push_str("\r\n");
+                    }
+                    let resp = format!(
+                        "HTTP/1.1 {} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n{extra}\r\n{}",
+                        reply.status,
+                        reply.body.len(),
+                        reply.body
+                    );
+                    let _ = sock.write_all(resp.as_bytes()).await;
+                    let _ = sock.shutdown().await;
+                });
+            }
+        });
+
+        Self {
+            base_url: format!("http://{addr}/query"),
+            requests,
+        }
+    }
+
+    /// Raw request heads received so far.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Request targets (path and query) received so far.
+    pub fn targets(&self) -> Vec<String> {
+        self.requests()
+            .iter()
+            .filter_map(|h| {
+                h.lines()
+                    .next()?
+                    .split_whitespace()
+                    .nth(1)
+                    .map(str::to_string)
+            })
+            .collect()
+    }
+}
+
+/// Value of `name` in the query string of `target`.
+pub fn query_param(target: &str, name: &str) -> Option<String> {
+    let query = target.split_once('?')?.1;
+    query.split('&').find_map(|kv| {
+        let (k, v) = kv.split_once('=')?;
+        (k == name).then(|| v.to_string())
+    })
+}
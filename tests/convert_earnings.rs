@@ -1,4 +1,3 @@
-/*
 use borsa_alphavantage::AvConnector;
 use borsa_core::BorsaConnector;
 use borsa_core::{
@@ -95,4 +94,3 @@ fn convert_earnings_periods_parse() {
         Period::Quarter { .. } | Period::Year { .. } | Period::Date(_)
     ));
 }
-*/
@@ -30,7 +30,8 @@ async fn crypto_symbol_handling() {
     let h = <dyn adapter::AvHistory>::from_fns(
         |_s, _r| unreachable!(),
         |_b, _q, _r| unreachable!(),
-        |_s, _r| {
+        |_b, market, _r| {
+            assert_eq!(market, "USD");
             Ok(HistoryResponse {
                 candles: vec![Candle {
                     ts: chrono::Utc.timestamp_opt(100, 0).unwrap(),
@@ -81,7 +82,7 @@ async fn crypto_different_intervals() {
     let h = <dyn adapter::AvHistory>::from_fns(
         |_s, _r| unreachable!(),
         |_b, _q, _r| unreachable!(),
-        |_s, _r| {
+        |_b, _m, _r| {
             Ok(HistoryResponse {
                 candles: vec![Candle {
                     ts: chrono::Utc.timestamp_opt(200, 0).unwrap(),
@@ -129,3 +130,67 @@ async fn crypto_different_intervals() {
         assert!(!response.adjusted);
     }
 }
+
+#[tokio::test]
+async fn crypto_pair_markets() {
+    let h = <dyn adapter::AvHistory>::from_fns(
+        |_s, _r| unreachable!(),
+        |_b, _q, _r| unreachable!(),
+        |base, market, _r| {
+            let currency = Currency::try_from_str(&market).unwrap();
+            let px = Money::from_canonical_str("3.0", currency).unwrap();
+            assert!(matches!(
+                (base.as_str(), market.as_str()),
+                ("BTC", "EUR") | ("ETH", "JPY")
+            ));
+            Ok(HistoryResponse {
+                candles: vec![Candle {
+                    ts: chrono::Utc.timestamp_opt(300, 0).unwrap(),
+                    open: px.clone(),
+                    high: px.clone(),
+                    low: px.clone(),
+                    close: px,
+                    close_unadj: None,
+                    volume: None,
+                }],
+                actions: vec![],
+                adjusted: false,
+                meta: None,
+            })
+        },
+    );
+    let av = AvConnector::from_adapter(&A { h });
+
+    for symbol in ["BTC/EUR", "ETH-JPY"] {
+        let inst =
+            Instrument::from_symbol(symbol, AssetKind::Crypto).expect("valid test instrument");
+        let req = HistoryRequest::try_from_range(borsa_core::Range::D1, Interval::D1).unwrap();
+        let response = av.history(&inst, req).await.unwrap();
+        assert_eq!(response.candles.len(), 1);
+    }
+}
+
+#[tokio::test]
+async fn crypto_pair_with_empty_side_rejected() {
+    let av = AvConnector::new_with_key("DUMMY");
+    let inst = Instrument::from_symbol("BTC/", AssetKind::Crypto).expect("valid test instrument");
+    let req = HistoryRequest::try_from_range(borsa_core::Range::D1, Interval::D1).unwrap();
+
+    let err = av.history(&inst, req).await.unwrap_err();
+    assert!(matches!(err, borsa_core::BorsaError::InvalidArg(_)));
+}
+
+#[tokio::test]
+async fn crypto_unservable_intervals_are_rejected() {
+    let av = AvConnector::new_with_key("DUMMY");
+    let inst = Instrument::from_symbol("BTC", AssetKind::Crypto).expect("valid test instrument");
+
+    for interval in [Interval::I2m, Interval::I90m, Interval::D5] {
+        let req = HistoryRequest::try_from_range(borsa_core::Range::D5, interval).unwrap();
+        let err = av.history(&inst, req).await.unwrap_err();
+        assert!(
+            matches!(err, borsa_core::BorsaError::Unsupported { .. }),
+            "{interval:?} should be unsupported, got {err:?}"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use borsa_alphavantage::api::time_series::{TimeSeriesResponse, csv_to_history};
+use borsa_alphavantage::window::HistoryWindow;
+use borsa_core::{BorsaError, Currency, HistoryResponse, IsoCurrency, Range};
+
+const USD: Currency = Currency::Iso(IsoCurrency::USD);
+const MAX: HistoryWindow = HistoryWindow::Range(Range::Max);
+
+fn from_json(body: &str) -> HistoryResponse {
+    let ts: TimeSeriesResponse = serde_json::from_str(body).expect("valid fixture");
+    ts.to_history(&USD, &MAX).unwrap()
+}
+
+#[test]
+fn intraday_csv_matches_the_json_series() {
+    let csv = csv_to_history(
+        include_str!("fixtures/intraday_5min.csv"),
+        "US/Eastern",
+        &USD,
+        &MAX,
+    )
+    .unwrap();
+    assert_eq!(csv, from_json(include_str!("fixtures/intraday_5min.json")));
+}
+
+#[test]
+fn adjusted_daily_csv_matches_the_json_series() {
+    let csv = csv_to_history(
+        include_str!("fixtures/daily_adjusted_aapl.csv"),
+        "US/Eastern",
+        &USD,
+        &MAX,
+    )
+    .unwrap();
+    let json = from_json(include_str!("fixtures/daily_adjusted_aapl.json"));
+    assert_eq!(csv, json);
+    assert!(csv.adjusted);
+    assert_eq!(csv.actions.len(), 1);
+}
+
+#[test]
+fn csv_is_trimmed_to_the_window() {
+    let resp = csv_to_history(
+        include_str!("fixtures/daily_adjusted_aapl.csv"),
+        "US/Eastern",
+        &USD,
+        &HistoryWindow::Range(Range::D1),
+    )
+    .unwrap();
+    assert_eq!(resp.candles.len(), 1);
+}
+
+#[test]
+fn empty_csv_has_no_candles() {
+    let resp = csv_to_history("", "UTC", &USD, &MAX).unwrap();
+    assert!(resp.candles.is_empty());
+    let resp = csv_to_history("timestamp,open,high,low,close\n", "UTC", &USD, &MAX).unwrap();
+    assert!(resp.candles.is_empty());
+}
+
+#[test]
+fn malformed_csv_is_a_data_error() {
+    let missing_column = csv_to_history("timestamp,open,high,close\n", "UTC", &USD, &MAX);
+    assert!(matches!(missing_column, Err(BorsaError::Data(_))));
+
+    let bad_row = csv_to_history(
+        "timestamp,open,high,low,close\n2024-05-03,1,2,x,1.5\n",
+        "UTC",
+        &USD,
+        &MAX,
+    );
+    match bad_row {
+        Err(BorsaError::Data(msg)) => assert!(msg.contains("row 2"), "{msg}"),
+        other => panic!("expected a data error, got {other:?}"),
+    }
+}
@@ -0,0 +1,62 @@
+use borsa_alphavantage::api::dividends::DividendsResponse;
+use borsa_core::Action;
+use chrono::{NaiveDate, TimeZone, Utc};
+
+fn load() -> DividendsResponse {
+    serde_json::from_str(include_str!("fixtures/dividends_ibm.json")).expect("valid fixture")
+}
+
+const fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn dividends_carry_all_four_dates() {
+    let dividends = load().to_dividends();
+    let latest = &dividends[0];
+    assert_eq!(latest.ex_date, date(2024, 5, 9));
+    assert_eq!(latest.declaration_date, Some(date(2024, 4, 30)));
+    assert_eq!(latest.record_date, Some(date(2024, 5, 10)));
+    assert_eq!(latest.payment_date, Some(date(2024, 6, 10)));
+    assert!((latest.amount - 1.67).abs() < 1e-9);
+}
+
+#[test]
+fn missing_dates_are_none_and_undated_events_are_dropped() {
+    let r = load();
+    assert_eq!(r.symbol.as_deref(), Some("IBM"));
+    assert_eq!(r.data.len(), 5);
+
+    let dividends = r.to_dividends();
+    assert_eq!(
+        dividends.len(),
+        4,
+        "the record without an ex-date is dropped"
+    );
+    let old = &dividends[3];
+    assert_eq!(old.ex_date, date(1999, 2, 8));
+    assert!(old.declaration_date.is_none());
+    assert!(old.record_date.is_none());
+    assert!(old.payment_date.is_none());
+    assert!(
+        (old.amount - 0.0275).abs() < 1e-12,
+        "full precision is kept"
+    );
+}
+
+#[test]
+fn dividend_action_is_stamped_at_the_start_of_the_ex_date() {
+    let Action::Dividend { ts, amount } = load().to_dividends()[0].to_action().unwrap() else {
+        panic!("expected a dividend action");
+    };
+    // Midnight US/Eastern during daylight saving time.
+    assert_eq!(ts, Utc.with_ymd_and_hms(2024, 5, 9, 4, 0, 0).unwrap());
+    assert_eq!(amount.amount().to_string(), "1.67");
+    assert_eq!(amount.currency().code(), "USD");
+}
+
+#[test]
+fn unknown_symbols_decode_to_no_dividends() {
+    let r: DividendsResponse = serde_json::from_str("{}").unwrap();
+    assert!(r.to_dividends().is_empty());
+}
@@ -0,0 +1,155 @@
+#![cfg(feature = "test-adapters")]
+
+mod common;
+
+use std::sync::Arc;
+
+use borsa_alphavantage::api::dividends::Dividend;
+use borsa_alphavantage::{AvConnector, adapter};
+use borsa_core::{
+    Action, AssetKind, BorsaError, HistoryRequest, Instrument, Interval, connector::HistoryProvider,
+};
+use chrono::NaiveDate;
+use common::{A, bars, equity, utc};
+
+const fn dividend(ex: (i32, u32, u32), amount: f64) -> Dividend {
+    Dividend {
+        ex_date: NaiveDate::from_ymd_opt(ex.0, ex.1, ex.2).unwrap(),
+        declaration_date: None,
+        record_date: None,
+        payment_date: None,
+        amount,
+    }
+}
+
+/// Daily bars from January to April 2024, as an unadjusted series returns them.
+fn daily() -> Arc<dyn adapter::AvHistory> {
+    bars(
+        &[utc(2024, 1, 2, 5), utc(2024, 2, 8, 5), utc(2024, 4, 30, 4)],
+        vec![],
+    )
+}
+
+fn ibm_dividends() -> Arc<dyn adapter::AvCorporateActions> {
+    <dyn adapter::AvCorporateActions>::from_fn(|s| {
+        assert_eq!(s, "IBM");
+        Ok(vec![
+            dividend((2024, 5, 9), 1.67),
+            dividend((2024, 2, 8), 1.66),
+            dividend((2023, 11, 9), 1.66),
+        ])
+    })
+}
+
+fn unadjusted() -> HistoryRequest {
+    HistoryRequest::builder()
+        .period(utc(2024, 1, 1, 0), utc(2024, 5, 1, 0))
+        .interval(Interval::D1)
+        .auto_adjust(false)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn unadjusted_history_gains_dividends_inside_the_window() {
+    let av = AvConnector::from_adapter(&A {
+        h: daily(),
+        c: ibm_dividends(),
+    })
+    .with_history_actions(true);
+    let resp = av.history(&equity("IBM"), unadjusted()).await.unwrap();
+    assert_eq!(resp.candles.len(), 3);
+    let [Action::Dividend { ts, amount }] = resp.actions.as_slice() else {
+        panic!("expected one dividend, got {:?}", resp.actions);
+    };
+    assert_eq!(*ts, utc(2024, 2, 8, 5), "midnight US/Eastern in winter");
+    assert_eq!(amount.amount().to_string(), "1.66");
+}
+
+#[tokio::test]
+async fn adjusted_and_action_free_requests_skip_the_dividend_lookup() {
+    let av = AvConnector::from_adapter(&A {
+        h: daily(),
+        c: <dyn adapter::AvCorporateActions>::from_fn(|_s| unreachable!()),
+    })
+    .with_history_actions(true);
+    let adjusted = HistoryRequest::builder()
+        .period(utc(2024, 1, 1, 0), utc(2024, 5, 1, 0))
+        .interval(Interval::D1)
+        .build()
+        .unwrap();
+    av.history(&equity("IBM"), adjusted).await.unwrap();
+
+    let without_actions = HistoryRequest::builder()
+        .period(utc(2024, 1, 1, 0), utc(2024, 5, 1, 0))
+        .interval(Interval::D1)
+        .auto_adjust(false)
+        .include_actions(false)
+        .build()
+        .unwrap();
+    let resp = av.history(&equity("IBM"), without_actions).await.unwrap();
+    assert!(resp.actions.is_empty());
+}
+
+#[tokio::test]
+async fn missing_dividend_record_keeps_the_bare_history() {
+    let av = AvConnector::from_adapter(&A {
+        h: daily(),
+        c: <dyn adapter::AvCorporateActions>::from_fn(|_s| {
+            Err(BorsaError::connector(
+                "borsa-alphavantage",
+                "Invalid API call. Please retry or visit the documentation for DIVIDENDS.",
+            ))
+        }),
+    })
+    .with_history_actions(true);
+    let resp = av.history(&equity("IBM"), unadjusted()).await.unwrap();
+    assert_eq!(resp.candles.len(), 3);
+    assert!(resp.actions.is_empty());
+}
+
+#[tokio::test]
+async fn dividend_lookup_is_off_by_default() {
+    let av = AvConnector::from_adapter(&A {
+        h: daily(),
+        c: <dyn adapter::AvCorporateActions>::from_fn(|_s| unreachable!()),
+    });
+    let resp = av.history(&equity("IBM"), unadjusted()).await.unwrap();
+    assert!(resp.actions.is_empty());
+}
+
+#[tokio::test]
+async fn dividend_rate_limits_keep_the_bare_history() {
+    let av = AvConnector::from_adapter(&A {
+        h: daily(),
+        c: <dyn adapter::AvCorporateActions>::from_fn(|_s| {
+            Err(BorsaError::connector(
+                "borsa-alphavantage",
+                "Thank you for using Alpha Vantage! Our standard API rate limit is 25 requests per day.",
+            ))
+        }),
+    })
+    .with_history_actions(true);
+    let resp = av.history(&equity("IBM"), unadjusted()).await.unwrap();
+    assert_eq!(resp.candles.len(), 3);
+    assert!(resp.actions.is_empty());
+}
+
+#[tokio::test]
+async fn dividends_use_injected_adapter() {
+    let av = AvConnector::from_adapter(&A {
+        h: daily(),
+        c: ibm_dividends(),
+    })
+    .with_history_actions(true);
+    let dividends = av.dividends(&equity("IBM")).await.unwrap();
+    assert_eq!(dividends.len(), 3);
+    assert_eq!(
+        dividends[0].ex_date,
+        NaiveDate::from_ymd_opt(2024, 5, 9).unwrap()
+    );
+
+    let fx = Instrument::from_symbol("EUR/USD", AssetKind::Forex).unwrap();
+    let err = av.dividends(&fx).await.unwrap_err();
+    assert!(matches!(err, BorsaError::Unsupported { .. }), "{err:?}");
+}
@@ -0,0 +1,100 @@
+use borsa_alphavantage::api::earnings::EarningsResponse;
+use borsa_alphavantage::api::statements::IncomeStatementResponse;
+use borsa_core::Period;
+use chrono::NaiveDate;
+
+fn load() -> EarningsResponse {
+    serde_json::from_str(include_str!("fixtures/earnings.json")).expect("valid fixture")
+}
+
+#[test]
+fn earnings_payload_decodes_all_eps_fields() {
+    let e = load();
+    assert_eq!(e.symbol, "IBM");
+    assert_eq!(e.annual_earnings.len(), 2);
+    assert_eq!(e.annual_earnings[0].reported_eps, Some(9.62));
+
+    let q = &e.quarterly_earnings[0];
+    assert_eq!(q.fiscal_date_ending, "2023-12-31");
+    assert_eq!(q.reported_date.as_deref(), Some("2024-01-24"));
+    assert_eq!(q.reported_eps, Some(3.87));
+    assert_eq!(q.estimated_eps, Some(3.78));
+    assert_eq!(q.surprise, Some(0.09));
+    assert_eq!(q.surprise_percentage, Some(2.381));
+    assert_eq!(q.report_time.as_deref(), Some("post-market"));
+}
+
+#[test]
+fn earnings_payload_treats_none_strings_as_missing() {
+    let e = load();
+    let old = e.quarterly_earnings.last().unwrap();
+    assert_eq!(old.reported_eps, Some(1.6));
+    assert_eq!(old.estimated_eps, None);
+    assert_eq!(old.surprise_percentage, None);
+}
+
+#[test]
+fn earnings_payload_maps_to_borsa_earnings() {
+    let earnings = load().to_earnings();
+
+    assert_eq!(
+        earnings.yearly.iter().map(|y| y.year).collect::<Vec<_>>(),
+        vec![2023, 2022]
+    );
+    assert_eq!(earnings.quarterly.len(), 3);
+    assert_eq!(earnings.quarterly_eps.len(), 3);
+
+    let latest = &earnings.quarterly_eps[0];
+    assert_eq!(
+        latest.period,
+        Period::Date(NaiveDate::from_ymd_opt(2023, 12, 31).unwrap())
+    );
+    assert_eq!(latest.actual.as_ref().unwrap().amount().to_string(), "3.87");
+    assert_eq!(
+        latest.estimate.as_ref().unwrap().amount().to_string(),
+        "3.78"
+    );
+    assert!(earnings.quarterly_eps[2].estimate.is_none());
+    assert!(earnings.yearly.iter().all(|y| y.revenue.is_none()));
+}
+
+#[test]
+fn earnings_join_income_statement_by_fiscal_period() {
+    let income: IncomeStatementResponse =
+        serde_json::from_str(include_str!("fixtures/income_statement_ibm.json")).unwrap();
+    let earnings = load().to_earnings_with(&income);
+    let amount = |m: &borsa_core::Money| m.amount().to_string();
+
+    let fy2023 = &earnings.yearly[0];
+    assert_eq!(
+        fy2023.revenue.as_ref().map(amount).as_deref(),
+        Some("61860000000")
+    );
+    assert_eq!(
+        fy2023.earnings.as_ref().map(amount).as_deref(),
+        Some("7502000000")
+    );
+    assert!(earnings.yearly[1].revenue.is_none(), "no FY2022 statement");
+
+    let q4 = &earnings.quarterly[0];
+    assert_eq!(
+        q4.revenue.as_ref().map(amount).as_deref(),
+        Some("17381000000")
+    );
+    assert_eq!(
+        q4.earnings.as_ref().map(amount).as_deref(),
+        Some("3288000000")
+    );
+    assert!(
+        earnings.quarterly[1].revenue.is_none(),
+        "no 2023-09-30 statement"
+    );
+    assert_eq!(earnings.quarterly_eps.len(), 3);
+}
+
+#[test]
+fn empty_earnings_payload_is_detected() {
+    let e: EarningsResponse = serde_json::from_str("{}").unwrap();
+    assert!(e.is_empty());
+    assert!(!load().is_empty());
+}
@@ -1,4 +1,3 @@
-/*
 #![cfg(feature = "test-adapters")]
 
 use std::sync::Arc;
@@ -51,7 +50,6 @@ async fn earnings_uses_injected_adapter() {
     assert_eq!(earnings.yearly.len(), 1);
 }
 
-
 #[tokio::test]
 async fn earnings_not_found_is_mapped() {
     let e = <dyn adapter::AvEarnings>::from_fn(|_s| {
@@ -66,4 +64,3 @@ async fn earnings_not_found_is_mapped() {
     let err = av.earnings(&inst).await.err().unwrap();
     assert!(matches!(err, BorsaError::NotFound { .. }));
 }
-*/
@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use borsa_alphavantage::error::AvError;
+use borsa_core::BorsaError;
+
+const MINUTE_NOTE: &str = "Thank you for using Alpha Vantage! Our standard API call frequency is 5 calls per minute and 500 calls per day.";
+const DAILY_INFO: &str = "We have detected your API key as *** and our standard API rate limit is 25 requests per day. Please subscribe to any of the premium plans at https://www.alphavantage.co/premium/ to instantly remove all daily rate limits.";
+const PREMIUM_INFO: &str = "Thank you for using Alpha Vantage! This is a premium endpoint. You may subscribe to any of the premium plans at https://www.alphavantage.co/premium/ to instantly unlock all premium endpoints";
+const BAD_KEY: &str = "the parameter apikey is invalid or missing. Please claim your free API key on (https://www.alphavantage.co/support/#api-key).";
+const BAD_SYMBOL: &str = "Invalid API call. Please retry or visit the documentation (https://www.alphavantage.co/documentation/) for TIME_SERIES_DAILY.";
+
+#[test]
+fn note_is_a_per_minute_rate_limit() {
+    let e = AvError::from_envelope("Note", MINUTE_NOTE);
+    assert!(matches!(e, AvError::RateLimited { .. }));
+    assert_eq!(e.retry_after(), Some(Duration::from_mins(1)));
+}
+
+#[test]
+fn daily_limit_information_hints_at_the_next_day() {
+    let e = AvError::from_envelope("Information", DAILY_INFO);
+    let wait = e.retry_after().expect("retry hint");
+    assert!(wait <= Duration::from_hours(24));
+    assert!(matches!(e, AvError::RateLimited { .. }));
+    assert!(e.is_daily_limit());
+    assert!(!AvError::from_envelope("Note", MINUTE_NOTE).is_daily_limit());
+}
+
+#[test]
+fn premium_invalid_key_and_not_found_are_distinct() {
+    assert!(matches!(
+        AvError::from_envelope("Information", PREMIUM_INFO),
+        AvError::PremiumRequired { .. }
+    ));
+    assert!(matches!(
+        AvError::from_envelope("Error Message", BAD_KEY),
+        AvError::InvalidKey { .. }
+    ));
+    assert!(matches!(
+        AvError::from_envelope("Error Message", BAD_SYMBOL),
+        AvError::NotFound { .. }
+    ));
+    assert!(matches!(
+        AvError::from_envelope("Information", "Something else happened"),
+        AvError::Api { .. }
+    ));
+}
+
+#[test]
+fn conversion_into_borsa_error_routes_by_class() {
+    let rate: BorsaError = AvError::from_envelope("Note", MINUTE_NOTE).into();
+    assert!(
+        matches!(&rate, BorsaError::Connector { connector, .. } if connector == "borsa-alphavantage")
+    );
+    assert!(rate.to_string().contains("retry after 60s"));
+
+    let premium: BorsaError = AvError::from_envelope("Information", PREMIUM_INFO).into();
+    assert!(matches!(premium, BorsaError::Unsupported { .. }));
+
+    let missing: BorsaError = AvError::from_envelope("Error Message", BAD_SYMBOL).into();
+    assert!(matches!(missing, BorsaError::NotFound { .. }));
+}
+
+#[test]
+fn class_survives_a_round_trip_through_borsa_error() {
+    for e in [
+        AvError::from_envelope("Note", MINUTE_NOTE),
+        AvError::from_envelope("Information", PREMIUM_INFO),
+        AvError::from_envelope("Error Message", BAD_KEY),
+        AvError::from_envelope("Error Message", BAD_SYMBOL),
+        AvError::HttpStatus { status: 404 },
+        AvError::Transport {
+            message: "connection refused".into(),
+        },
+    ] {
+        let back = AvError::from_borsa(&BorsaError::from(e.clone()));
+        assert_eq!(back, Some(e));
+    }
+    assert_eq!(AvError::from_borsa(&BorsaError::Data("x".into())), None);
+}
@@ -0,0 +1,78 @@
+use borsa_alphavantage::api::exchange_rate::ExchangeRateResponse;
+use borsa_core::{BorsaError, Currency, IsoCurrency};
+use chrono::{TimeZone, Utc};
+
+fn load(body: &str) -> ExchangeRateResponse {
+    serde_json::from_str(body).expect("valid fixture")
+}
+
+#[test]
+fn forex_rate_decodes_bid_ask_and_timestamp() {
+    let resp = load(include_str!("fixtures/exchange_rate_eurusd.json"));
+    let rate = resp.rate.as_ref().unwrap();
+    assert_eq!(rate.from_code, "EUR");
+    assert_eq!(rate.to_code, "USD");
+    assert_eq!(rate.to_name.as_deref(), Some("United States Dollar"));
+    assert_eq!(rate.exchange_rate, Some(1.0725));
+    assert_eq!(rate.bid_price, Some(1.0724));
+    assert_eq!(rate.ask_price, Some(1.0726));
+    assert_eq!(
+        rate.timestamp(),
+        Some(Utc.with_ymd_and_hms(2024, 5, 3, 21, 55, 1).unwrap())
+    );
+}
+
+#[test]
+fn forex_rate_maps_to_a_quote_in_the_quote_currency() {
+    let quote = load(include_str!("fixtures/exchange_rate_eurusd.json"))
+        .to_quote("EUR/USD")
+        .unwrap();
+    assert_eq!(quote.symbol.as_str(), "EUR/USD");
+    // `Money` rounds to minor units; the full rate stays on the payload.
+    let price = quote.price.unwrap();
+    assert_eq!(price.amount().to_string(), "1.07");
+    assert_eq!(price.currency(), &Currency::Iso(IsoCurrency::USD));
+    assert!(quote.previous_close.is_none());
+}
+
+#[test]
+fn crypto_rate_without_a_book_keeps_the_rate() {
+    let resp = load(include_str!("fixtures/exchange_rate_btceur.json"));
+    let rate = resp.rate.as_ref().unwrap();
+    assert_eq!(rate.bid_price, None);
+    assert_eq!(rate.ask_price, None);
+
+    let quote = resp.to_quote("BTC/EUR").unwrap();
+    let price = quote.price.unwrap();
+    assert_eq!(price.amount().to_string(), "58074.01");
+    assert_eq!(price.currency(), &Currency::Iso(IsoCurrency::EUR));
+}
+
+#[test]
+fn book_midpoint_stands_in_for_a_missing_rate() {
+    let mut resp = load(include_str!("fixtures/exchange_rate_eurusd.json"));
+    resp.rate.as_mut().unwrap().exchange_rate = None;
+    assert_eq!(resp.rate.as_ref().unwrap().price(), Some(1.0725));
+}
+
+#[test]
+fn missing_rate_is_not_found() {
+    let resp = load("{}");
+    assert!(matches!(
+        resp.to_quote("XXX/YYY"),
+        Err(BorsaError::NotFound { .. })
+    ));
+}
+
+#[test]
+fn jpy_rates_keep_their_decimals() {
+    let resp = load(include_str!("fixtures/exchange_rate_usdjpy.json"));
+    let rate = resp.to_exchange_rate("USD/JPY").unwrap();
+    assert_eq!(rate.rate().to_string(), "151.237");
+    assert_eq!(rate.from(), &Currency::Iso(IsoCurrency::USD));
+    assert_eq!(rate.to(), &Currency::Iso(IsoCurrency::JPY));
+
+    // The quote is `Money`, rounded to whole yen.
+    let quote = resp.to_quote("USD/JPY").unwrap();
+    assert_eq!(quote.price.unwrap().amount().to_string(), "151");
+}
@@ -54,7 +54,7 @@ async fn history_equity_maps_correctly() {
             })
         },
         |_b, _q, _r| unreachable!(),
-        |_s, _r| unreachable!(),
+        |_b, _m, _r| unreachable!(),
     );
     let av = AvConnector::from_adapter(&A { h });
     let inst = Instrument::from_symbol("AAPL", AssetKind::Equity).expect("valid test instrument");
@@ -99,7 +99,7 @@ async fn history_forex_maps_correctly() {
                 meta: None,
             })
         },
-        |_s, _r| unreachable!(),
+        |_b, _m, _r| unreachable!(),
     );
     let av = AvConnector::from_adapter(&A { h });
     let inst = Instrument::from_symbol("EUR/USD", AssetKind::Forex).expect("valid test instrument");
@@ -113,7 +113,7 @@ async fn history_crypto_maps_correctly() {
     let h = <dyn adapter::AvHistory>::from_fns(
         |_s, _r| unreachable!(),
         |_b, _q, _r| unreachable!(),
-        |_s, _r| {
+        |_b, _m, _r| {
             Ok(HistoryResponse {
                 candles: vec![Candle {
                     ts: chrono::Utc.timestamp_opt(3, 0).unwrap(),
@@ -165,7 +165,7 @@ async fn history_unsupported_interval_returns_error() {
             }
         },
         |_b, _q, _r| unreachable!(),
-        |_s, _r| unreachable!(),
+        |_b, _m, _r| unreachable!(),
     );
     let av = AvConnector::from_adapter(&A { h });
     let inst = Instrument::from_symbol("AAPL", AssetKind::Equity).expect("valid test instrument");
@@ -210,7 +210,7 @@ async fn forex_symbol_normalization() {
                 meta: None,
             })
         },
-        |_s, _r| unreachable!(),
+        |_b, _m, _r| unreachable!(),
     );
     let av = AvConnector::from_adapter(&A { h });
 
@@ -303,7 +303,7 @@ async fn forex_crypto_pairs() {
                 meta: None,
             })
         },
-        |_s, _r| unreachable!(),
+        |_b, _m, _r| unreachable!(),
     );
     let av = AvConnector::from_adapter(&A { h });
 
@@ -375,7 +375,7 @@ async fn forex_dash_delimiter() {
                 meta: None,
             })
         },
-        |_s, _r| unreachable!(),
+        |_b, _m, _r| unreachable!(),
     );
     let av = AvConnector::from_adapter(&A { h });
 
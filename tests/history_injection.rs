@@ -53,7 +53,7 @@ async fn history_equity_maps_correctly() {
             })
         },
         |_b, _q, _r| unreachable!(),
-        |_s, _r| unreachable!(),
+        |_b, _m, _r| unreachable!(),
     );
     let av = AvConnector::from_adapter(&A { h });
     let inst = Instrument::from_symbol("AAPL", AssetKind::Equity).expect("valid test instrument");
@@ -98,7 +98,7 @@ async fn history_forex_maps_correctly() {
                 meta: None,
             })
         },
-        |_s, _r| unreachable!(),
+        |_b, _m, _r| unreachable!(),
     );
     let av = AvConnector::from_adapter(&A { h });
     let inst = Instrument::from_symbol("EUR/USD", AssetKind::Forex).expect("valid test instrument");
@@ -112,7 +112,7 @@ async fn history_crypto_maps_correctly() {
     let h = <dyn adapter::AvHistory>::from_fns(
         |_s, _r| unreachable!(),
         |_b, _q, _r| unreachable!(),
-        |_s, _r| {
+        |_b, _m, _r| {
             Ok(HistoryResponse {
                 candles: vec![Candle {
                     ts: chrono::Utc.timestamp_opt(3, 0).unwrap(),
@@ -164,7 +164,7 @@ async fn history_unsupported_interval_returns_error() {
             }
         },
         |_b, _q, _r| unreachable!(),
-        |_s, _r| unreachable!(),
+        |_b, _m, _r| unreachable!(),
     );
     let av = AvConnector::from_adapter(&A { h });
     let inst = Instrument::from_symbol("AAPL", AssetKind::Equity).expect("valid test instrument");
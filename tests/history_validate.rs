@@ -61,7 +61,7 @@ async fn history_validation_works() {
             })
         },
         |_b, _q, _r| unreachable!(),
-        |_s, _r| unreachable!(),
+        |_b, _m, _r| unreachable!(),
     );
     let av = AvConnector::from_adapter(&A { h });
     let inst = Instrument::from_symbol("AAPL", AssetKind::Equity).expect("valid test instrument");
@@ -111,7 +111,7 @@ async fn history_validation_rejects_invalid_requests() {
             })
         },
         |_b, _q, _r| unreachable!(),
-        |_s, _r| unreachable!(),
+        |_b, _m, _r| unreachable!(),
     );
     let av = AvConnector::from_adapter(&A2 { h });
     let inst = Instrument::from_symbol("AAPL", AssetKind::Equity).expect("valid test instrument");
@@ -0,0 +1,211 @@
+use borsa_alphavantage::window::{DEFAULT_MAX_INTRADAY_MONTHS, HistoryWindow};
+use borsa_core::{
+    Action, BorsaError, Candle, Currency, HistoryRequest, HistoryResponse, Interval, IsoCurrency,
+    Money, Range,
+};
+use chrono::{DateTime, TimeZone, Utc};
+
+fn at(y: i32, m: u32, d: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap()
+}
+
+fn usd(v: &str) -> Money {
+    Money::from_canonical_str(v, Currency::Iso(IsoCurrency::USD)).unwrap()
+}
+
+fn candle(ts: DateTime<Utc>) -> Candle {
+    Candle {
+        ts,
+        open: usd("1.0"),
+        high: usd("1.0"),
+        low: usd("1.0"),
+        close: usd("1.0"),
+        close_unadj: None,
+        volume: None,
+    }
+}
+
+fn daily_response() -> HistoryResponse {
+    HistoryResponse {
+        candles: (1..=20).map(|d| candle(at(2024, 1, d))).collect(),
+        actions: vec![
+            Action::Dividend {
+                ts: at(2024, 1, 3),
+                amount: usd("0.5"),
+            },
+            Action::Dividend {
+                ts: at(2024, 1, 18),
+                amount: usd("0.5"),
+            },
+        ],
+        adjusted: true,
+        meta: None,
+    }
+}
+
+#[test]
+fn range_window_is_anchored_at_latest_candle() {
+    let req = HistoryRequest::try_from_range(Range::D5, Interval::D1).unwrap();
+    let window = HistoryWindow::from_request(&req);
+    assert_eq!(window, HistoryWindow::Range(Range::D5));
+
+    let mut resp = daily_response();
+    window.trim(&mut resp);
+    let days: Vec<_> = resp.candles.iter().map(|c| c.ts).collect();
+    assert_eq!(days, (16..=20).map(|d| at(2024, 1, d)).collect::<Vec<_>>());
+    assert_eq!(resp.actions.len(), 1);
+}
+
+#[test]
+fn period_window_is_half_open() {
+    let req =
+        HistoryRequest::try_from_period(at(2024, 1, 3), at(2024, 1, 6), Interval::D1).unwrap();
+    let mut resp = daily_response();
+    HistoryWindow::from_request(&req).trim(&mut resp);
+    let days: Vec<_> = resp.candles.iter().map(|c| c.ts).collect();
+    assert_eq!(days, vec![at(2024, 1, 3), at(2024, 1, 4), at(2024, 1, 5)]);
+    assert_eq!(resp.actions.len(), 1);
+}
+
+#[test]
+fn max_and_ytd_ranges() {
+    let mut resp = daily_response();
+    HistoryWindow::Range(Range::Max).trim(&mut resp);
+    assert_eq!(resp.candles.len(), 20);
+
+    let mut resp = daily_response();
+    resp.candles.insert(0, candle(at(2023, 12, 29)));
+    HistoryWindow::Range(Range::Ytd).trim(&mut resp);
+    assert_eq!(resp.candles.first().unwrap().ts, at(2024, 1, 1));
+}
+
+#[test]
+fn compact_is_chosen_only_when_100_points_cover_the_window() {
+    let now = at(2024, 6, 1);
+
+    assert!(HistoryWindow::Range(Range::D5).fits_compact(Interval::D1, now));
+    assert!(HistoryWindow::Range(Range::M3).fits_compact(Interval::D1, now));
+    assert!(!HistoryWindow::Range(Range::Y1).fits_compact(Interval::D1, now));
+    assert!(!HistoryWindow::Range(Range::Max).fits_compact(Interval::D1, now));
+
+    // 100 one-minute bars cannot cover a full day, 100 hourly bars can.
+    assert!(!HistoryWindow::Range(Range::D1).fits_compact(Interval::I1m, now));
+    assert!(HistoryWindow::Range(Range::D1).fits_compact(Interval::I1h, now));
+
+    // Compact always returns the latest points, so an old period needs full output.
+    let old = HistoryWindow::Period {
+        start: at(2020, 1, 1),
+        end: at(2020, 1, 10),
+    };
+    assert!(!old.fits_compact(Interval::D1, now));
+    let recent = HistoryWindow::Period {
+        start: at(2024, 5, 1),
+        end: at(2024, 5, 10),
+    };
+    assert!(recent.fits_compact(Interval::D1, now));
+
+    // Weekly and monthly series have no compact variant.
+    assert!(!HistoryWindow::Range(Range::D5).fits_compact(Interval::W1, now));
+}
+
+#[test]
+fn recent_intraday_windows_need_no_month_slices() {
+    let now = at(2024, 6, 15);
+    assert!(
+        HistoryWindow::Range(Range::D5)
+            .intraday_months(now, DEFAULT_MAX_INTRADAY_MONTHS)
+            .unwrap()
+            .is_empty()
+    );
+    assert!(
+        HistoryWindow::Range(Range::Max)
+            .intraday_months(now, DEFAULT_MAX_INTRADAY_MONTHS)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn historical_intraday_windows_fan_out_per_month() {
+    let now = at(2024, 6, 15);
+    let window = HistoryWindow::Period {
+        start: at(2023, 11, 20),
+        end: at(2024, 2, 1),
+    };
+    assert_eq!(
+        window
+            .intraday_months(now, DEFAULT_MAX_INTRADAY_MONTHS)
+            .unwrap(),
+        vec!["2023-11", "2023-12", "2024-01"]
+    );
+
+    // Ranges reach up to the current month.
+    assert_eq!(
+        HistoryWindow::Range(Range::M3)
+            .intraday_months(now, DEFAULT_MAX_INTRADAY_MONTHS)
+            .unwrap(),
+        vec!["2024-03", "2024-04", "2024-05", "2024-06"]
+    );
+
+    // Month slices start in 2000.
+    let window = HistoryWindow::Period {
+        start: at(1998, 1, 1),
+        end: at(2000, 3, 1),
+    };
+    assert_eq!(
+        window
+            .intraday_months(now, DEFAULT_MAX_INTRADAY_MONTHS)
+            .unwrap(),
+        vec!["2000-01", "2000-02"]
+    );
+}
+
+#[test]
+fn month_slices_follow_us_eastern_calendar_months() {
+    let now = at(2024, 6, 15);
+    // 02:00 UTC on Nov 1 is still Oct 31 in New York; 03:00 UTC on Feb 1 is Jan 31.
+    let window = HistoryWindow::Period {
+        start: Utc.with_ymd_and_hms(2023, 11, 1, 2, 0, 0).unwrap(),
+        end: Utc.with_ymd_and_hms(2024, 2, 1, 3, 0, 0).unwrap(),
+    };
+    assert_eq!(
+        window
+            .intraday_months(now, DEFAULT_MAX_INTRADAY_MONTHS)
+            .unwrap(),
+        vec!["2023-10", "2023-11", "2023-12", "2024-01"]
+    );
+}
+
+#[test]
+fn month_fan_out_is_capped() {
+    let now = at(2024, 6, 15);
+    assert_eq!(
+        HistoryWindow::Range(Range::Y2)
+            .intraday_months(now, DEFAULT_MAX_INTRADAY_MONTHS)
+            .unwrap()
+            .len(),
+        DEFAULT_MAX_INTRADAY_MONTHS
+    );
+    let err = HistoryWindow::Range(Range::Y10)
+        .intraday_months(now, DEFAULT_MAX_INTRADAY_MONTHS)
+        .unwrap_err();
+    assert!(matches!(err, BorsaError::InvalidArg(_)), "{err:?}");
+}
+
+#[test]
+fn month_fan_out_cap_is_configurable() {
+    let now = at(2024, 6, 15);
+    assert_eq!(
+        HistoryWindow::Range(Range::Y10)
+            .intraday_months(now, 130)
+            .unwrap()
+            .len(),
+        121
+    );
+    let window = HistoryWindow::Range(Range::M6);
+    assert_eq!(window.intraday_months(now, 7).unwrap().len(), 7);
+    assert!(matches!(
+        window.intraday_months(now, 6),
+        Err(BorsaError::InvalidArg(_))
+    ));
+}
@@ -0,0 +1,590 @@
+mod common;
+
+use std::time::Duration;
+
+use borsa_alphavantage::error::AvError;
+use borsa_alphavantage::rate_limit::RateLimitTier;
+use borsa_alphavantage::retry::RetryPolicy;
+use borsa_alphavantage::{AvConnector, Datatype};
+use borsa_core::{
+    AssetKind, BorsaError, HistoryRequest, Instrument, Interval, Range, SearchRequest,
+    connector::{
+        BalanceSheetProvider, CashflowProvider, EarningsProvider, HistoryProvider,
+        IncomeStatementProvider, ProfileProvider, QuoteProvider, SearchProvider,
+    },
+};
+use chrono::{TimeZone, Utc};
+use common::{Reply, StandIn, query_param};
+
+const GLOBAL_QUOTE: &str = include_str!("fixtures/global_quote_ibm.json");
+
+fn equity(symbol: &str) -> Instrument {
+    Instrument::from_symbol(symbol, AssetKind::Equity).expect("valid test instrument")
+}
+
+fn connector(server: &StandIn) -> AvConnector {
+    AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .build()
+        .expect("connector")
+}
+
+#[tokio::test]
+async fn native_endpoint_uses_the_base_url() {
+    let server = StandIn::start(vec![(
+        "EARNINGS",
+        vec![Reply::json(include_str!("fixtures/earnings.json"))],
+    )])
+    .await;
+    let av = connector(&server);
+
+    let earnings = av.earnings(&equity("IBM")).await.unwrap();
+    assert!(!earnings.quarterly_eps.is_empty());
+
+    let targets = server.targets();
+    assert_eq!(targets.len(), 1);
+    assert_eq!(query_param(&targets[0], "symbol").as_deref(), Some("IBM"));
+    assert_eq!(
+        query_param(&targets[0], "apikey").as_deref(),
+        Some("TESTKEY")
+    );
+}
+
+#[tokio::test]
+async fn quote_endpoint_uses_the_base_url() {
+    let server = StandIn::start(vec![("GLOBAL_QUOTE", vec![Reply::json(GLOBAL_QUOTE)])]).await;
+    let av = connector(&server);
+
+    let quote = av.quote(&equity("IBM")).await.unwrap();
+    assert_eq!(quote.symbol.as_str(), "IBM");
+    assert_eq!(quote.price.unwrap().amount().to_string(), "168.15");
+    assert_eq!(server.targets().len(), 1);
+}
+
+#[tokio::test]
+async fn pair_quotes_use_currency_exchange_rate() {
+    let server = StandIn::start(vec![(
+        "CURRENCY_EXCHANGE_RATE",
+        vec![Reply::json(include_str!(
+            "fixtures/exchange_rate_eurusd.json"
+        ))],
+    )])
+    .await;
+    let av = connector(&server);
+
+    let inst = Instrument::from_symbol("EUR/USD", AssetKind::Forex).unwrap();
+    let quote = av.quote(&inst).await.unwrap();
+    assert_eq!(quote.symbol.as_str(), "EUR/USD");
+    assert_eq!(quote.price.unwrap().amount().to_string(), "1.07");
+
+    let target = &server.targets()[0];
+    assert_eq!(query_param(target, "from_currency").as_deref(), Some("EUR"));
+    assert_eq!(query_param(target, "to_currency").as_deref(), Some("USD"));
+}
+
+#[tokio::test]
+async fn exchange_rates_are_not_rounded_to_minor_units() {
+    let server = StandIn::start(vec![(
+        "CURRENCY_EXCHANGE_RATE",
+        vec![Reply::json(include_str!(
+            "fixtures/exchange_rate_usdjpy.json"
+        ))],
+    )])
+    .await;
+    let av = connector(&server);
+
+    let inst = Instrument::from_symbol("USD/JPY", AssetKind::Forex).unwrap();
+    let rate = av.exchange_rate(&inst).await.unwrap();
+    assert_eq!(rate.rate().to_string(), "151.237");
+
+    let err = av.exchange_rate(&equity("IBM")).await.unwrap_err();
+    assert!(matches!(err, BorsaError::Unsupported { .. }), "{err:?}");
+    assert_eq!(server.targets().len(), 1);
+}
+
+#[tokio::test]
+async fn bulk_quotes_are_chunked_and_report_missing_symbols() {
+    let server = StandIn::start(vec![(
+        "REALTIME_BULK_QUOTES",
+        vec![
+            Reply::json(include_str!("fixtures/realtime_bulk_quotes.json")),
+            Reply::json(r#"{"data": []}"#),
+        ],
+    )])
+    .await;
+    let av = connector(&server);
+
+    let mut symbols = vec!["MSFT".to_string(), "AAPL".to_string()];
+    symbols.extend((0..149).map(|n| format!("S{n}")));
+    symbols.push("msft".to_string());
+    let instruments: Vec<Instrument> = symbols
+        .iter()
+        .map(|s| Instrument::from_symbol(s, AssetKind::Equity).unwrap())
+        .collect();
+    let results = av.quotes(&instruments).await;
+
+    assert_eq!(results.len(), 152);
+    assert_eq!(
+        results[0]
+            .as_ref()
+            .unwrap()
+            .price
+            .as_ref()
+            .unwrap()
+            .amount()
+            .to_string(),
+        "406.66"
+    );
+    assert!(results[1].is_ok());
+    assert!(results[151].is_ok(), "duplicates share the first row");
+    assert!(
+        results[2..151]
+            .iter()
+            .all(|r| matches!(r, Err(BorsaError::NotFound { .. })))
+    );
+
+    let sizes: Vec<usize> = server
+        .targets()
+        .iter()
+        .map(|t| query_param(t, "symbol").unwrap().split("%2C").count())
+        .collect();
+    assert_eq!(sizes, vec![100, 51]);
+}
+
+#[tokio::test]
+async fn malformed_bulk_rows_only_fail_their_own_symbol() {
+    let server = StandIn::start(vec![(
+        "REALTIME_BULK_QUOTES",
+        vec![Reply::json(
+            r#"{"data": [
+                {"symbol": "MSFT", "close": "406.6600"},
+                {"symbol": "IBM", "close": "1e300"},
+                {"symbol": "AAPL", "close": 183.38},
+                {"close": "1.0"}
+            ]}"#,
+        )],
+    )])
+    .await;
+    let av = connector(&server);
+
+    let results = av
+        .quotes(&[equity("MSFT"), equity("IBM"), equity("AAPL")])
+        .await;
+    assert!(results[0].is_ok());
+    for r in &results[1..] {
+        assert!(matches!(r, Err(BorsaError::Data(_))), "{r:?}");
+    }
+}
+
+#[tokio::test]
+async fn profile_is_read_from_overview() {
+    let server = StandIn::start(vec![("OVERVIEW", vec![Reply::json("{}")])]).await;
+    let av = connector(&server);
+
+    let err = av.profile(&equity("NOPE")).await.unwrap_err();
+    assert!(matches!(err, BorsaError::NotFound { .. }), "{err:?}");
+    assert_eq!(
+        query_param(&server.targets()[0], "symbol").as_deref(),
+        Some("NOPE")
+    );
+}
+
+#[tokio::test]
+async fn statements_are_read_from_their_endpoints() {
+    let server = StandIn::start(vec![
+        (
+            "INCOME_STATEMENT",
+            vec![Reply::json(include_str!(
+                "fixtures/income_statement_ibm.json"
+            ))],
+        ),
+        (
+            "BALANCE_SHEET",
+            vec![Reply::json(include_str!("fixtures/balance_sheet_ibm.json"))],
+        ),
+        ("CASH_FLOW", vec![Reply::json("{}")]),
+    ])
+    .await;
+    let av = connector(&server);
+
+    assert_eq!(
+        av.income_statement(&equity("IBM"), true)
+            .await
+            .unwrap()
+            .len(),
+        2
+    );
+    assert_eq!(
+        av.balance_sheet(&equity("IBM"), false).await.unwrap().len(),
+        1
+    );
+    let err = av.cashflow(&equity("IBM"), false).await.unwrap_err();
+    assert!(matches!(err, BorsaError::NotFound { .. }), "{err:?}");
+    assert_eq!(server.targets().len(), 3);
+}
+
+#[tokio::test]
+async fn intraday_query_is_built_from_the_request() {
+    let server = StandIn::start(vec![(
+        "TIME_SERIES_INTRADAY",
+        vec![Reply::json(include_str!("fixtures/intraday_5min.json"))],
+    )])
+    .await;
+    let av = connector(&server);
+
+    let req = HistoryRequest::try_from_range(Range::D1, Interval::I15m).unwrap();
+    let resp = av.history(&equity("IBM"), req).await.unwrap();
+    assert!(!resp.candles.is_empty());
+
+    let target = &server.targets()[0];
+    assert_eq!(query_param(target, "interval").as_deref(), Some("15min"));
+    assert_eq!(
+        query_param(target, "outputsize").as_deref(),
+        Some("compact")
+    );
+    assert_eq!(
+        query_param(target, "extended_hours").as_deref(),
+        Some("false")
+    );
+}
+
+#[tokio::test]
+async fn error_envelopes_become_typed_errors() {
+    let server = StandIn::start(vec![
+        (
+            "EARNINGS",
+            vec![Reply::json(
+                r#"{"Information": "Thank you for using Alpha Vantage! This is a premium endpoint."}"#,
+            )],
+        ),
+        (
+            "GLOBAL_QUOTE",
+            vec![Reply::json(
+                r#"{"Error Message": "Invalid API call. Please retry or visit the documentation."}"#,
+            )],
+        ),
+    ])
+    .await;
+    let av = connector(&server);
+
+    let premium = av.earnings(&equity("IBM")).await.unwrap_err();
+    assert!(
+        matches!(premium, BorsaError::Unsupported { .. }),
+        "{premium:?}"
+    );
+
+    let missing = av.quote(&equity("NOPE")).await.unwrap_err();
+    assert!(
+        matches!(missing, BorsaError::NotFound { .. }),
+        "{missing:?}"
+    );
+}
+
+#[tokio::test]
+async fn server_errors_are_retried() {
+    let server = StandIn::start(vec![(
+        "EARNINGS",
+        vec![
+            Reply::status(503),
+            Reply::json(include_str!("fixtures/earnings.json")),
+        ],
+    )])
+    .await;
+    let av = connector(&server);
+
+    av.earnings(&equity("IBM")).await.unwrap();
+    assert_eq!(server.targets().len(), 2);
+}
+
+fn fast_retry_connector(server: &StandIn) -> AvConnector {
+    AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .retry_policy(
+            RetryPolicy::default().backoff(Duration::from_millis(10), Duration::from_millis(50)),
+        )
+        .build()
+        .expect("connector")
+}
+
+#[tokio::test]
+async fn per_minute_rate_limits_are_retried() {
+    let server = StandIn::start(vec![(
+        "EARNINGS",
+        vec![
+            Reply::json(
+                r#"{"Note": "Thank you for using Alpha Vantage! Our standard API call frequency is 5 calls per minute and 500 calls per day."}"#,
+            ),
+            Reply::json(include_str!("fixtures/earnings.json")),
+        ],
+    )])
+    .await;
+    let av = fast_retry_connector(&server);
+
+    av.earnings(&equity("IBM")).await.unwrap();
+    assert_eq!(server.targets().len(), 2);
+}
+
+#[tokio::test]
+async fn retry_after_is_capped_by_the_policy() {
+    let server = StandIn::start(vec![(
+        "EARNINGS",
+        vec![
+            Reply::status(429).header("Retry-After", "60"),
+            Reply::json(include_str!("fixtures/earnings.json")),
+        ],
+    )])
+    .await;
+    let av = fast_retry_connector(&server);
+
+    av.earnings(&equity("IBM")).await.unwrap();
+    assert_eq!(server.targets().len(), 2);
+}
+
+#[tokio::test]
+async fn daily_rate_limits_are_not_retried() {
+    let server = StandIn::start(vec![(
+        "EARNINGS",
+        vec![Reply::json(
+            r#"{"Information": "We have detected your API key as TESTKEY and our standard API rate limit is 25 requests per day."}"#,
+        )],
+    )])
+    .await;
+    let av = fast_retry_connector(&server);
+
+    let err = av.earnings(&equity("IBM")).await.unwrap_err();
+    assert!(
+        AvError::from_borsa(&err).is_some_and(|e| e.is_daily_limit()),
+        "{err:?}"
+    );
+    assert_eq!(server.targets().len(), 1);
+}
+
+#[tokio::test]
+async fn http_404_is_not_an_unknown_symbol() {
+    let server = StandIn::start(vec![]).await;
+    let av = connector(&server);
+
+    let err = av.quote(&equity("IBM")).await.unwrap_err();
+    assert!(
+        matches!(
+            AvError::from_borsa(&err),
+            Some(AvError::HttpStatus { status: 404 })
+        ),
+        "{err:?}"
+    );
+    assert_eq!(server.targets().len(), 1);
+}
+
+#[tokio::test]
+async fn not_found_is_not_retried() {
+    let server = StandIn::start(vec![(
+        "EARNINGS",
+        vec![Reply::json(r#"{"Error Message": "Invalid API call."}"#)],
+    )])
+    .await;
+    let av = connector(&server);
+
+    assert!(av.earnings(&equity("IBM")).await.is_err());
+    assert_eq!(server.targets().len(), 1);
+}
+
+#[tokio::test]
+async fn search_filters_by_kind_and_limit() {
+    let server = StandIn::start(vec![(
+        "SYMBOL_SEARCH",
+        vec![Reply::json(include_str!(
+            "fixtures/symbol_search_tesco.json"
+        ))],
+    )])
+    .await;
+    let av = connector(&server);
+
+    let req = SearchRequest::builder("tesco")
+        .kind(AssetKind::Equity)
+        .limit(2)
+        .build()
+        .unwrap();
+    let resp = av.search(req).await.unwrap();
+    let symbols: Vec<_> = resp.results.iter().map(|r| r.symbol.as_str()).collect();
+    assert_eq!(symbols, ["TSCO.LON", "TSCDF"]);
+    assert_eq!(
+        query_param(&server.targets()[0], "keywords").as_deref(),
+        Some("tesco")
+    );
+}
+
+#[tokio::test]
+async fn history_requests_pick_the_endpoint_for_kind_and_interval() {
+    let series = include_str!("fixtures/weekly_adjusted_ibm.json");
+    let server = StandIn::start(vec![
+        ("TIME_SERIES_WEEKLY_ADJUSTED", vec![Reply::json(series)]),
+        ("TIME_SERIES_MONTHLY", vec![Reply::json(series)]),
+        (
+            "DIVIDENDS",
+            vec![Reply::json(include_str!("fixtures/dividends_ibm.json"))],
+        ),
+        (
+            "SPLITS",
+            vec![Reply::json(include_str!("fixtures/splits_nvda.json"))],
+        ),
+        (
+            "FX_DAILY",
+            vec![Reply::json(include_str!("fixtures/fx_daily_eurjpy.json"))],
+        ),
+        (
+            "DIGITAL_CURRENCY_DAILY",
+            vec![Reply::json(include_str!(
+                "fixtures/digital_currency_daily_btc.json"
+            ))],
+        ),
+    ])
+    .await;
+    let av = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .rate_limit(RateLimitTier::Unlimited)
+        .history_actions(true)
+        .build()
+        .expect("connector");
+    let max = |interval| HistoryRequest::try_from_range(Range::Max, interval).unwrap();
+
+    av.history(&equity("IBM"), max(Interval::W1)).await.unwrap();
+    let unadjusted = HistoryRequest::builder()
+        .range(Range::Max)
+        .interval(Interval::M1)
+        .auto_adjust(false)
+        .build()
+        .unwrap();
+    av.history(&equity("IBM"), unadjusted).await.unwrap();
+    let fx = Instrument::from_symbol("EUR/JPY", AssetKind::Forex).unwrap();
+    av.history(&fx, max(Interval::D1)).await.unwrap();
+    let btc = Instrument::from_symbol("BTC/EUR", AssetKind::Crypto).unwrap();
+    av.history(&btc, max(Interval::D1)).await.unwrap();
+
+    let targets = server.targets();
+    let param = |i: usize, name| query_param(&targets[i], name);
+    assert_eq!(
+        param(0, "function").as_deref(),
+        Some("TIME_SERIES_WEEKLY_ADJUSTED")
+    );
+    assert_eq!(param(1, "function").as_deref(), Some("SPLITS"));
+    assert_eq!(param(2, "function").as_deref(), Some("TIME_SERIES_MONTHLY"));
+    assert_eq!(param(3, "function").as_deref(), Some("DIVIDENDS"));
+    assert_eq!(param(4, "function").as_deref(), Some("SPLITS"));
+    assert_eq!(param(5, "function").as_deref(), Some("FX_DAILY"));
+    assert_eq!(param(5, "from_symbol").as_deref(), Some("EUR"));
+    assert_eq!(param(5, "to_symbol").as_deref(), Some("JPY"));
+    assert_eq!(param(5, "outputsize").as_deref(), Some("full"));
+    assert_eq!(
+        param(6, "function").as_deref(),
+        Some("DIGITAL_CURRENCY_DAILY")
+    );
+    assert_eq!(param(6, "symbol").as_deref(), Some("BTC"));
+    assert_eq!(param(6, "market").as_deref(), Some("EUR"));
+    assert_eq!(targets.len(), 7);
+}
+
+#[tokio::test]
+async fn quarters_are_resampled_before_the_window_is_applied() {
+    let bar = |open: &str, volume: &str| {
+        format!(
+            r#"{{"1. open": "{open}", "2. high": "{open}", "3. low": "{open}", "4. close": "{open}", "5. volume": "{volume}"}}"#
+        )
+    };
+    let body = format!(
+        r#"{{"Meta Data": {{"4. Time Zone": "US/Eastern"}},
+            "Monthly Time Series": {{
+                "2024-01-31": {}, "2024-02-29": {}, "2024-03-28": {},
+                "2024-04-30": {}, "2024-05-31": {}, "2024-06-28": {}
+            }}}}"#,
+        bar("10", "1"),
+        bar("20", "2"),
+        bar("30", "4"),
+        bar("40", "8"),
+        bar("50", "16"),
+        bar("60", "32"),
+    );
+    let server = StandIn::start(vec![("TIME_SERIES_MONTHLY", vec![Reply::json(body)])]).await;
+    let av = connector(&server);
+
+    let req = HistoryRequest::builder()
+        .period(
+            Utc.with_ymd_and_hms(2024, 2, 15, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap(),
+        )
+        .interval(Interval::M3)
+        .auto_adjust(false)
+        .build()
+        .unwrap();
+    let resp = av.history(&equity("IBM"), req).await.unwrap();
+
+    assert_eq!(resp.candles.len(), 2);
+    let q1 = &resp.candles[0];
+    assert_eq!(q1.open.amount().to_string(), "10", "Q1 opens in January");
+    assert_eq!(q1.low.amount().to_string(), "10");
+    assert_eq!(q1.volume, Some(7));
+    assert_eq!(resp.candles[1].volume, Some(56));
+}
+
+#[tokio::test]
+async fn csv_datatype_is_requested_and_parsed() {
+    let server = StandIn::start(vec![(
+        "TIME_SERIES_DAILY_ADJUSTED",
+        vec![Reply::json(include_str!(
+            "fixtures/daily_adjusted_aapl.csv"
+        ))],
+    )])
+    .await;
+    let av = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .datatype(Datatype::Csv)
+        .build()
+        .unwrap();
+
+    let req = HistoryRequest::try_from_range(Range::Max, Interval::D1).unwrap();
+    let resp = av.history(&equity("AAPL"), req).await.unwrap();
+    assert_eq!(resp.candles.len(), 4);
+    assert_eq!(
+        query_param(&server.targets()[0], "datatype").as_deref(),
+        Some("csv")
+    );
+}
+
+#[tokio::test]
+async fn csv_requests_still_surface_json_envelopes() {
+    let server = StandIn::start(vec![(
+        "TIME_SERIES_DAILY_ADJUSTED",
+        vec![Reply::json(
+            r#"{"Error Message": "Invalid API call. Please retry or visit the documentation."}"#,
+        )],
+    )])
+    .await;
+    let av = AvConnector::builder()
+        .api_key("TESTKEY")
+        .base_url(&server.base_url)
+        .datatype(Datatype::Csv)
+        .build()
+        .unwrap();
+
+    let req = HistoryRequest::try_from_range(Range::Max, Interval::D1).unwrap();
+    let err = av.history(&equity("NOPE"), req).await.unwrap_err();
+    assert!(matches!(err, BorsaError::NotFound { .. }), "{err:?}");
+}
+
+#[test]
+fn builder_requires_a_key_and_a_valid_url() {
+    assert!(matches!(
+        AvConnector::builder().build(),
+        Err(BorsaError::InvalidArg(_))
+    ));
+    assert!(matches!(
+        AvConnector::builder()
+            .api_key("K")
+            .base_url("not a url")
+            .build(),
+        Err(BorsaError::InvalidArg(_))
+    ));
+}
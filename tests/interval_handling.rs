@@ -69,7 +69,7 @@ async fn intraday_intervals_supported() {
             })
         },
         |_b, _q, _r| unreachable!(),
-        |_s, _r| unreachable!(),
+        |_b, _m, _r| unreachable!(),
     );
     let av = AvConnector::from_adapter(&A { h });
     let inst = Instrument::from_symbol("AAPL", AssetKind::Equity).expect("valid test instrument");
@@ -125,7 +125,7 @@ async fn daily_intervals_supported() {
             })
         },
         |_b, _q, _r| unreachable!(),
-        |_s, _r| unreachable!(),
+        |_b, _m, _r| unreachable!(),
     );
     let av = AvConnector::from_adapter(&A { h });
     let inst = Instrument::from_symbol("AAPL", AssetKind::Equity).expect("valid test instrument");
@@ -172,7 +172,7 @@ async fn weekly_intervals_supported() {
             })
         },
         |_b, _q, _r| unreachable!(),
-        |_s, _r| unreachable!(),
+        |_b, _m, _r| unreachable!(),
     );
     let av = AvConnector::from_adapter(&A2 { h });
     let inst = Instrument::from_symbol("AAPL", AssetKind::Equity).expect("valid test instrument");
@@ -218,7 +218,7 @@ async fn monthly_intervals_supported() {
             })
         },
         |_b, _q, _r| unreachable!(),
-        |_s, _r| unreachable!(),
+        |_b, _m, _r| unreachable!(),
     );
     let av = AvConnector::from_adapter(&A3 { h });
     let inst = Instrument::from_symbol("AAPL", AssetKind::Equity).expect("valid test instrument");
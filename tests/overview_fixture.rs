@@ -0,0 +1,76 @@
+use borsa_alphavantage::api::overview::OverviewResponse;
+use borsa_core::{BorsaError, FundKind, Profile};
+
+fn load(body: &str) -> OverviewResponse {
+    serde_json::from_str(body).expect("valid fixture")
+}
+
+#[test]
+fn overview_payload_decodes_descriptive_fields() {
+    let o = load(include_str!("fixtures/overview_ibm.json"));
+    assert_eq!(o.symbol.as_deref(), Some("IBM"));
+    assert_eq!(o.cik.as_deref(), Some("51143"));
+    assert_eq!(o.exchange.as_deref(), Some("NYSE"));
+    assert_eq!(o.currency.as_deref(), Some("USD"));
+    assert_eq!(o.fiscal_year_end.as_deref(), Some("December"));
+    assert_eq!(o.latest_quarter.as_deref(), Some("2024-03-31"));
+    assert!(!o.is_empty());
+}
+
+#[test]
+fn common_stock_maps_to_a_company_profile() {
+    let Profile::Company(c) = load(include_str!("fixtures/overview_ibm.json"))
+        .to_profile()
+        .unwrap()
+    else {
+        panic!("expected a company profile");
+    };
+    assert_eq!(c.name, "International Business Machines");
+    assert_eq!(c.sector.as_deref(), Some("TECHNOLOGY"));
+    assert_eq!(c.industry.as_deref(), Some("COMPUTER & OFFICE EQUIPMENT"));
+    assert_eq!(c.website.as_deref(), Some("https://www.ibm.com"));
+    assert!(
+        c.summary
+            .unwrap()
+            .starts_with("International Business Machines")
+    );
+
+    let address = c.address.unwrap();
+    assert_eq!(address.street1.as_deref(), Some("1 NEW ORCHARD ROAD"));
+    assert_eq!(address.city.as_deref(), Some("ARMONK"));
+    assert_eq!(address.state.as_deref(), Some("NY"));
+    assert_eq!(address.country.as_deref(), Some("USA"));
+    assert_eq!(address.zip, None);
+}
+
+#[test]
+fn short_address_is_kept_as_the_street() {
+    let mut o = load(include_str!("fixtures/overview_ibm.json"));
+    o.address = Some("PO BOX 1, ZUG".into());
+    o.country = None;
+    let Profile::Company(c) = o.to_profile().unwrap() else {
+        panic!("expected a company profile");
+    };
+    let address = c.address.unwrap();
+    assert_eq!(address.street1.as_deref(), Some("PO BOX 1, ZUG"));
+    assert_eq!(address.city, None);
+    assert_eq!(address.country, None);
+}
+
+#[test]
+fn etf_maps_to_a_fund_profile() {
+    let o = load(include_str!("fixtures/overview_spy.json"));
+    assert_eq!(o.sector, None, "\"None\" placeholders decode as absent");
+    let Profile::Fund(f) = o.to_profile().unwrap() else {
+        panic!("expected a fund profile");
+    };
+    assert_eq!(f.name, "SPDR S&P 500 ETF Trust");
+    assert_eq!(f.kind, FundKind::Etf);
+}
+
+#[test]
+fn unknown_symbol_decodes_to_an_empty_overview() {
+    let o = load("{}");
+    assert!(o.is_empty());
+    assert!(matches!(o.to_profile(), Err(BorsaError::Data(_))));
+}
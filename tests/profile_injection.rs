@@ -0,0 +1,53 @@
+#![cfg(feature = "test-adapters")]
+
+use std::sync::Arc;
+
+use borsa_alphavantage::{AvConnector, adapter};
+use borsa_core::{
+    AssetKind, BorsaError, CompanyProfile, Instrument, Profile, connector::ProfileProvider,
+};
+
+struct A {
+    p: Arc<dyn adapter::AvProfile>,
+}
+impl adapter::CloneArcAdapters for A {
+    fn clone_arc_profile(&self) -> Arc<dyn adapter::AvProfile> {
+        self.p.clone()
+    }
+}
+
+#[tokio::test]
+async fn profile_uses_injected_adapter() {
+    let p = <dyn adapter::AvProfile>::from_fn(|s| {
+        assert_eq!(s, "IBM");
+        Ok(Profile::Company(CompanyProfile {
+            name: "International Business Machines".into(),
+            sector: Some("TECHNOLOGY".into()),
+            industry: None,
+            website: None,
+            address: None,
+            summary: None,
+            isin: None,
+        }))
+    });
+    let av = AvConnector::from_adapter(&A { p });
+    let inst = Instrument::from_symbol("IBM", AssetKind::Equity).expect("valid test instrument");
+    let Profile::Company(c) = av.profile(&inst).await.unwrap() else {
+        panic!("expected a company profile");
+    };
+    assert_eq!(c.sector.as_deref(), Some("TECHNOLOGY"));
+}
+
+#[tokio::test]
+async fn profile_not_found_is_mapped() {
+    let p = <dyn adapter::AvProfile>::from_fn(|_s| {
+        Err(BorsaError::connector(
+            "borsa-alphavantage",
+            "Invalid API call. Please retry or visit the documentation for OVERVIEW.",
+        ))
+    });
+    let av = AvConnector::from_adapter(&A { p });
+    let inst = Instrument::from_symbol("NOPE", AssetKind::Equity).expect("valid test instrument");
+    let err = av.profile(&inst).await.unwrap_err();
+    assert!(matches!(err, BorsaError::NotFound { .. }), "{err:?}");
+}
@@ -0,0 +1,62 @@
+use borsa_alphavantage::api::quote::GlobalQuoteResponse;
+use borsa_core::MarketState;
+use chrono::{NaiveDate, TimeZone, Utc};
+
+fn load() -> GlobalQuoteResponse {
+    serde_json::from_str(include_str!("fixtures/global_quote_ibm.json")).expect("valid fixture")
+}
+
+#[test]
+fn global_quote_payload_decodes_every_field() {
+    let q = load().quote;
+    assert_eq!(q.symbol.as_deref(), Some("IBM"));
+    assert_eq!(q.open, Some(167.5));
+    assert_eq!(q.high, Some(168.99));
+    assert_eq!(q.low, Some(166.8));
+    assert_eq!(q.price, Some(168.15));
+    assert_eq!(q.volume, Some(3_176_435.0));
+    assert_eq!(q.latest_trading_day.as_deref(), Some("2024-05-03"));
+    assert_eq!(q.previous_close, Some(165.93));
+    assert_eq!(q.change, Some(2.22));
+    assert_eq!(q.change_percent.as_deref(), Some("1.3379%"));
+}
+
+#[test]
+fn global_quote_payload_maps_to_a_usd_quote() {
+    let quote = load().to_quote().unwrap();
+    assert_eq!(quote.symbol.as_str(), "IBM");
+    assert_eq!(quote.price.unwrap().amount().to_string(), "168.15");
+    assert_eq!(quote.previous_close.unwrap().amount().to_string(), "165.93");
+}
+
+#[test]
+fn global_quote_payload_exposes_derived_fields() {
+    let q = load().quote;
+    assert_eq!(q.trading_day(), NaiveDate::from_ymd_opt(2024, 5, 3));
+    assert_eq!(q.change_percent_value(), Some(1.3379));
+    assert_eq!(q.day_range(), Some((166.8, 168.99)));
+}
+
+#[test]
+fn market_state_comes_from_the_exchange_clock() {
+    let resp = load();
+    // Friday 2024-05-03, 11:00 EDT.
+    let during = Utc.with_ymd_and_hms(2024, 5, 3, 15, 0, 0).unwrap();
+    let quote = resp.to_quote_at(during).unwrap();
+    assert_eq!(quote.market_state, Some(MarketState::Regular));
+    assert!(!resp.quote.is_stale_at(during));
+
+    // Monday 2024-05-06, 10:00 EDT, with the previous weekday still the
+    // latest trading day, as end-of-day data is all session.
+    let stale = Utc.with_ymd_and_hms(2024, 5, 6, 14, 0, 0).unwrap();
+    let quote = resp.to_quote_at(stale).unwrap();
+    assert_eq!(quote.market_state, Some(MarketState::Regular));
+    assert!(resp.quote.is_stale_at(stale));
+}
+
+#[test]
+fn unknown_symbol_decodes_to_an_empty_quote() {
+    let resp: GlobalQuoteResponse = serde_json::from_str(r#"{"Global Quote": {}}"#).unwrap();
+    assert!(resp.is_empty());
+    assert!(!load().is_empty());
+}
@@ -81,3 +81,113 @@ async fn quote_error_preserves_connector_name() {
         _ => panic!("Expected Connector error, got: {err:?}"),
     }
 }
+
+fn pair_quotes() -> Arc<dyn adapter::AvQuotes> {
+    <dyn adapter::AvQuotes>::from_fns(
+        |_s| Err(BorsaError::unsupported("equity quote in a pair test")),
+        |from, to| {
+            let currency = borsa_core::Currency::try_from_str(&to).unwrap();
+            Ok(borsa_core::Quote {
+                symbol: borsa_core::Symbol::new(&format!("{from}/{to}")).unwrap(),
+                shortname: None,
+                price: Some(borsa_core::Money::from_canonical_str("1.5", currency).unwrap()),
+                previous_close: None,
+                exchange: None,
+                market_state: None,
+            })
+        },
+    )
+}
+
+#[tokio::test]
+async fn forex_quotes_use_the_pair_endpoint() {
+    let av = AvConnector::from_adapter(&TestAdapter { q: pair_quotes() });
+
+    let inst = Instrument::from_symbol("EUR-JPY", AssetKind::Forex).expect("valid test instrument");
+    let quote = av.quote(&inst).await.unwrap();
+    assert_eq!(quote.symbol.as_str(), "EUR-JPY");
+    assert_eq!(quote.price.unwrap().currency().to_string(), "JPY");
+}
+
+#[tokio::test]
+async fn bare_crypto_quotes_are_priced_in_usd() {
+    let av = AvConnector::from_adapter(&TestAdapter { q: pair_quotes() });
+
+    let inst = Instrument::from_symbol("BTC", AssetKind::Crypto).expect("valid test instrument");
+    let quote = av.quote(&inst).await.unwrap();
+    assert_eq!(quote.symbol.as_str(), "BTC");
+    assert_eq!(quote.price.unwrap().currency().to_string(), "USD");
+}
+
+#[tokio::test]
+async fn forex_quote_without_a_pair_is_rejected() {
+    let av = AvConnector::from_adapter(&TestAdapter { q: pair_quotes() });
+
+    let inst = Instrument::from_symbol("EURUSD", AssetKind::Forex).expect("valid test instrument");
+    assert!(matches!(
+        av.quote(&inst).await,
+        Err(BorsaError::InvalidArg(_))
+    ));
+}
+
+fn usd_quote(s: &str) -> borsa_core::Quote {
+    borsa_core::Quote {
+        symbol: borsa_core::Symbol::new(s).unwrap(),
+        shortname: None,
+        price: Some(
+            borsa_core::Money::from_canonical_str(
+                "10",
+                borsa_core::Currency::Iso(borsa_core::IsoCurrency::USD),
+            )
+            .unwrap(),
+        ),
+        previous_close: None,
+        exchange: None,
+        market_state: None,
+    }
+}
+
+fn equities(symbols: impl IntoIterator<Item = String>) -> Vec<Instrument> {
+    symbols
+        .into_iter()
+        .map(|s| Instrument::from_symbol(&s, AssetKind::Equity).expect("valid test instrument"))
+        .collect()
+}
+
+#[tokio::test]
+async fn bulk_quotes_report_failures_per_chunk() {
+    let q = <dyn adapter::AvQuotes>::from_fn(|s| {
+        if s == "BAD" {
+            Err(BorsaError::connector("borsa-alphavantage", "HTTP 500"))
+        } else {
+            Ok(usd_quote(&s))
+        }
+    });
+    let av = AvConnector::from_adapter(&TestAdapter { q });
+
+    // The 101st symbol opens a second chunk, which fails on its own.
+    let mut symbols: Vec<String> = (0..100).map(|n| format!("S{n}")).collect();
+    symbols.push("BAD".into());
+    let results = av.quotes(&equities(symbols)).await;
+
+    assert_eq!(results.len(), 101);
+    assert!(results[..100].iter().all(Result::is_ok));
+    assert!(matches!(results[100], Err(BorsaError::Connector { .. })));
+}
+
+#[tokio::test]
+async fn bulk_quotes_keep_input_order_and_spelling() {
+    let q = <dyn adapter::AvQuotes>::from_fn(|s| Ok(usd_quote(&s)));
+    let av = AvConnector::from_adapter(&TestAdapter { q });
+
+    let mut instruments = equities(["MSFT".to_string(), "ibm".to_string()]);
+    instruments.push(Instrument::from_symbol("EUR/USD", AssetKind::Forex).unwrap());
+    let results = av.quotes(&instruments).await;
+
+    assert_eq!(results[0].as_ref().unwrap().symbol.as_str(), "MSFT");
+    assert_eq!(
+        results[1].as_ref().unwrap().symbol,
+        *instruments[1].symbol()
+    );
+    assert!(matches!(results[2], Err(BorsaError::Unsupported { .. })));
+}
@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use borsa_alphavantage::rate_limit::{RateLimitTier, RateLimiter, WhenExhausted};
+use borsa_core::BorsaError;
+use tokio::time::Instant;
+
+#[test]
+fn tiers_carry_alpha_vantage_budgets() {
+    assert_eq!(RateLimitTier::Free.per_minute(), Some(5));
+    assert_eq!(RateLimitTier::Free.per_day(), Some(25));
+    let premium = RateLimitTier::Premium { per_minute: 75 };
+    assert_eq!(premium.per_minute(), Some(75));
+    assert_eq!(premium.per_day(), None);
+    assert_eq!(RateLimitTier::Unlimited.per_minute(), None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn queued_calls_wait_for_the_next_minute_token() {
+    let limiter = RateLimiter::new(RateLimitTier::Free, WhenExhausted::Queue);
+    let start = Instant::now();
+    for _ in 0..5 {
+        limiter.acquire().await.unwrap();
+    }
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    // Five per minute refill one token every 12 seconds.
+    limiter.acquire().await.unwrap();
+    let waited = start.elapsed();
+    assert!(
+        waited >= Duration::from_secs(12) && waited < Duration::from_secs(13),
+        "waited {waited:?}"
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn fail_fast_reports_a_retry_hint() {
+    let limiter = RateLimiter::new(
+        RateLimitTier::Premium { per_minute: 2 },
+        WhenExhausted::FailFast,
+    );
+    limiter.acquire().await.unwrap();
+    limiter.acquire().await.unwrap();
+    let err = limiter.acquire().await.unwrap_err();
+    match err {
+        BorsaError::Connector { msg, .. } => {
+            assert!(msg.contains("per-minute"), "{msg}");
+            assert!(msg.contains("retry after 30s"), "{msg}");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+
+    tokio::time::advance(Duration::from_secs(30)).await;
+    limiter.acquire().await.unwrap();
+}
+
+#[tokio::test(start_paused = true)]
+async fn daily_budget_fails_even_when_queueing() {
+    let limiter = RateLimiter::new(RateLimitTier::Free, WhenExhausted::Queue);
+    for _ in 0..25 {
+        limiter.acquire().await.unwrap();
+    }
+    let err = limiter.acquire().await.unwrap_err();
+    assert!(err.to_string().contains("daily"), "{err}");
+}
+
+#[tokio::test(start_paused = true)]
+async fn clones_share_one_budget() {
+    let a = RateLimiter::new(
+        RateLimitTier::Premium { per_minute: 1 },
+        WhenExhausted::FailFast,
+    );
+    let b = a.clone();
+    a.acquire().await.unwrap();
+    assert!(b.acquire().await.is_err());
+}
+
+#[tokio::test(start_paused = true)]
+async fn unlimited_never_waits() {
+    let limiter = RateLimiter::new(RateLimitTier::Unlimited, WhenExhausted::FailFast);
+    for _ in 0..1000 {
+        limiter.acquire().await.unwrap();
+    }
+}
@@ -0,0 +1,68 @@
+use borsa_alphavantage::resample::monthly_to_quarterly;
+use borsa_core::{Candle, Currency, HistoryMeta, HistoryResponse, IsoCurrency, Money};
+use chrono::{TimeZone, Utc};
+
+fn usd(v: &str) -> Money {
+    Money::from_canonical_str(v, Currency::Iso(IsoCurrency::USD)).unwrap()
+}
+
+fn month(
+    (year, month, day): (i32, u32, u32),
+    [open, high, low, close]: [&str; 4],
+    volume: u64,
+) -> Candle {
+    Candle {
+        ts: Utc.with_ymd_and_hms(year, month, day, 4, 0, 0).unwrap(),
+        open: usd(open),
+        high: usd(high),
+        low: usd(low),
+        close: usd(close),
+        close_unadj: None,
+        volume: Some(volume),
+    }
+}
+
+const fn monthly(candles: Vec<Candle>) -> HistoryResponse {
+    HistoryResponse {
+        candles,
+        actions: vec![],
+        adjusted: false,
+        meta: Some(HistoryMeta {
+            timezone: Some(chrono_tz::Tz::US__Eastern),
+            utc_offset_seconds: None,
+        }),
+    }
+}
+
+#[test]
+fn months_collapse_into_calendar_quarters() {
+    let resp = monthly_to_quarterly(monthly(vec![
+        month((2024, 4, 30), ["13", "15", "12", "14"], 30),
+        month((2024, 1, 31), ["10", "12", "9", "11"], 10),
+        month((2024, 2, 29), ["11", "16", "10", "12"], 20),
+        month((2024, 3, 28), ["12", "13", "8", "13"], 5),
+    ]))
+    .unwrap();
+
+    assert_eq!(resp.candles.len(), 2);
+    let q1 = &resp.candles[0];
+    assert_eq!(q1.ts, Utc.with_ymd_and_hms(2024, 3, 28, 4, 0, 0).unwrap());
+    assert_eq!(q1.open, usd("10"));
+    assert_eq!(q1.high, usd("16"));
+    assert_eq!(q1.low, usd("8"));
+    assert_eq!(q1.close, usd("13"));
+    assert_eq!(q1.volume, Some(35));
+
+    // Partial trailing quarter is kept.
+    let q2 = &resp.candles[1];
+    assert_eq!(q2.open, usd("13"));
+    assert_eq!(q2.close, usd("14"));
+    assert_eq!(q2.volume, Some(30));
+}
+
+#[test]
+fn empty_series_resamples_to_empty() {
+    let resp = monthly_to_quarterly(monthly(vec![])).unwrap();
+    assert!(resp.candles.is_empty());
+    assert!(resp.meta.is_some());
+}
@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+use borsa_alphavantage::retry::RetryPolicy;
+
+#[test]
+fn backoff_doubles_up_to_the_cap() {
+    let p = RetryPolicy::default()
+        .backoff(Duration::from_millis(100), Duration::from_secs(1))
+        .jitter(false);
+    let delays: Vec<u128> = (0..6).map(|n| p.delay(n).as_millis()).collect();
+    assert_eq!(delays, vec![100, 200, 400, 800, 1000, 1000]);
+}
+
+#[test]
+fn jittered_delays_stay_in_the_upper_half() {
+    let p = RetryPolicy::default().backoff(Duration::from_millis(400), Duration::from_secs(10));
+    for _ in 0..200 {
+        let d = p.delay(1);
+        assert!(
+            d >= Duration::from_millis(400) && d <= Duration::from_millis(800),
+            "{d:?}"
+        );
+    }
+}
+
+#[test]
+fn attempts_are_at_least_one() {
+    assert_eq!(RetryPolicy::none().attempts(), 1);
+    assert_eq!(RetryPolicy::default().max_attempts(0).attempts(), 1);
+    assert_eq!(RetryPolicy::default().attempts(), 3);
+}
+
+#[test]
+fn huge_retry_counts_do_not_overflow() {
+    let p = RetryPolicy::default()
+        .backoff(Duration::from_secs(1), Duration::from_secs(5))
+        .jitter(false);
+    assert_eq!(p.delay(u32::MAX), Duration::from_secs(5));
+}
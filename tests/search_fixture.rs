@@ -0,0 +1,34 @@
+use borsa_alphavantage::api::search::SearchResponse;
+
+fn load() -> SearchResponse {
+    serde_json::from_str(include_str!("fixtures/symbol_search_tesco.json")).expect("valid fixture")
+}
+
+#[test]
+fn symbol_search_payload_decodes_every_field() {
+    let resp = load();
+    assert_eq!(resp.best_matches.len(), 4);
+    let m = &resp.best_matches[0];
+    assert_eq!(m.symbol, "TSCO.LON");
+    assert_eq!(m.name, "Tesco PLC");
+    assert_eq!(m.kind, "Equity");
+    assert_eq!(m.region, "United Kingdom");
+    assert_eq!(m.market_open.as_deref(), Some("08:00"));
+    assert_eq!(m.market_close.as_deref(), Some("16:30"));
+    assert_eq!(m.timezone.as_deref(), Some("UTC+01"));
+    assert_eq!(m.currency.as_deref(), Some("GBX"));
+    assert_eq!(m.match_score, Some(0.7273));
+}
+
+#[test]
+fn symbol_search_payload_treats_none_as_missing() {
+    let fund = &load().best_matches[3];
+    assert_eq!(fund.kind, "Mutual Fund");
+    assert_eq!(fund.currency, None);
+}
+
+#[test]
+fn empty_search_decodes_to_no_matches() {
+    let resp: SearchResponse = serde_json::from_str(r#"{"bestMatches": []}"#).unwrap();
+    assert!(resp.best_matches.is_empty());
+}
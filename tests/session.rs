@@ -0,0 +1,53 @@
+use borsa_alphavantage::session::{us_equity_quote_is_stale, us_equity_session};
+use borsa_core::MarketState;
+use chrono::{NaiveDate, TimeZone, Utc};
+
+#[test]
+fn us_equity_sessions_follow_eastern_time() {
+    // Tuesday 2024-01-09, EST (UTC-5).
+    let utc = |h, m| Utc.with_ymd_and_hms(2024, 1, 9, h, m, 0).unwrap();
+    assert_eq!(us_equity_session(utc(8, 59)), MarketState::Closed);
+    assert_eq!(us_equity_session(utc(9, 0)), MarketState::Pre);
+    assert_eq!(us_equity_session(utc(14, 25)), MarketState::Pre);
+    assert_eq!(us_equity_session(utc(14, 30)), MarketState::Regular);
+    assert_eq!(us_equity_session(utc(20, 55)), MarketState::Regular);
+    assert_eq!(us_equity_session(utc(21, 0)), MarketState::Post);
+    assert_eq!(
+        us_equity_session(Utc.with_ymd_and_hms(2024, 1, 10, 0, 55, 0).unwrap()),
+        MarketState::Post
+    );
+    assert_eq!(
+        us_equity_session(Utc.with_ymd_and_hms(2024, 1, 10, 1, 0, 0).unwrap()),
+        MarketState::Closed
+    );
+}
+
+#[test]
+fn us_equity_sessions_handle_daylight_saving() {
+    // Tuesday 2024-07-09, EDT (UTC-4): the regular open is 13:30 UTC.
+    let utc = |h, m| Utc.with_ymd_and_hms(2024, 7, 9, h, m, 0).unwrap();
+    assert_eq!(us_equity_session(utc(13, 25)), MarketState::Pre);
+    assert_eq!(us_equity_session(utc(13, 30)), MarketState::Regular);
+    assert_eq!(us_equity_session(utc(20, 0)), MarketState::Post);
+}
+
+#[test]
+fn weekends_are_closed() {
+    let saturday_noon = Utc.with_ymd_and_hms(2024, 1, 13, 17, 0, 0).unwrap();
+    assert_eq!(us_equity_session(saturday_noon), MarketState::Closed);
+}
+
+#[test]
+fn quotes_lag_once_a_newer_session_opens() {
+    let tuesday = NaiveDate::from_ymd_opt(2024, 1, 9).unwrap();
+    let utc = |d, h, m| Utc.with_ymd_and_hms(2024, 1, d, h, m, 0).unwrap();
+    // Same day: regular and post-market are current.
+    assert!(!us_equity_quote_is_stale(tuesday, utc(9, 15, 0)));
+    assert!(!us_equity_quote_is_stale(tuesday, utc(9, 22, 0)));
+    // Wednesday pre-market has not traded yet; the regular session has.
+    assert!(!us_equity_quote_is_stale(tuesday, utc(10, 10, 0)));
+    assert!(us_equity_quote_is_stale(tuesday, utc(10, 15, 0)));
+    // Saturday after a Friday session.
+    let friday = NaiveDate::from_ymd_opt(2024, 1, 12).unwrap();
+    assert!(!us_equity_quote_is_stale(friday, utc(13, 17, 0)));
+}
@@ -0,0 +1,89 @@
+use borsa_alphavantage::api::splits::SplitsResponse;
+use borsa_core::Action;
+use chrono::{TimeZone, Utc};
+
+fn load() -> SplitsResponse {
+    serde_json::from_str(include_str!("fixtures/splits_nvda.json")).expect("valid fixture")
+}
+
+fn ratios(actions: &[Action]) -> Vec<(u32, u32)> {
+    actions
+        .iter()
+        .filter_map(|a| match a {
+            Action::Split {
+                numerator,
+                denominator,
+                ..
+            } => Some((*numerator, *denominator)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn split_factors_become_exact_ratios() {
+    let r = load();
+    assert_eq!(r.symbol.as_deref(), Some("NVDA"));
+    assert_eq!(r.data.len(), 5);
+
+    let actions = r.to_actions().unwrap();
+    assert_eq!(ratios(&actions), [(10, 1), (4, 1), (3, 2), (2, 1)]);
+}
+
+#[test]
+fn splits_are_stamped_at_the_start_of_the_effective_date() {
+    let actions = load().to_actions().unwrap();
+    let Action::Split { ts, .. } = &actions[0] else {
+        panic!("expected a split");
+    };
+    // Midnight US/Eastern during daylight saving time.
+    assert_eq!(*ts, Utc.with_ymd_and_hms(2024, 6, 10, 4, 0, 0).unwrap());
+}
+
+#[test]
+fn reverse_and_no_op_factors() {
+    let r: SplitsResponse = serde_json::from_str(
+        r#"{"symbol": "X", "data": [
+            {"effective_date": "2020-01-02", "split_factor": "0.1000"},
+            {"effective_date": "2020-01-03", "split_factor": "1.0000"},
+            {"effective_date": "2020-01-06", "split_factor": "None"}
+        ]}"#,
+    )
+    .unwrap();
+    assert_eq!(ratios(&r.to_actions().unwrap()), [(1, 10)]);
+}
+
+#[test]
+fn four_decimal_factors_match_thirds() {
+    let r: SplitsResponse = serde_json::from_str(
+        r#"{"symbol": "X", "data": [
+            {"effective_date": "2020-01-02", "split_factor": "1.3333"},
+            {"effective_date": "2020-01-03", "split_factor": "0.3333"},
+            {"effective_date": "2020-01-06", "split_factor": "0.6667"}
+        ]}"#,
+    )
+    .unwrap();
+    assert_eq!(ratios(&r.to_actions().unwrap()), [(4, 3), (1, 3), (2, 3)]);
+}
+
+#[test]
+fn deep_reverse_splits_keep_round_denominators() {
+    let r: SplitsResponse = serde_json::from_str(
+        r#"{"symbol": "X", "data": [
+            {"effective_date": "2020-01-02", "split_factor": "0.0050"},
+            {"effective_date": "2020-01-03", "split_factor": "0.0067"},
+            {"effective_date": "2020-01-06", "split_factor": "0.1429"}
+        ]}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        ratios(&r.to_actions().unwrap()),
+        [(1, 200), (1, 150), (1, 7)]
+    );
+}
+
+#[test]
+fn unknown_symbols_decode_to_no_splits() {
+    let r: SplitsResponse = serde_json::from_str("{}").unwrap();
+    assert!(r.to_actions().unwrap().is_empty());
+}
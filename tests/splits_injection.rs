@@ -0,0 +1,162 @@
+#![cfg(feature = "test-adapters")]
+
+mod common;
+
+use std::sync::Arc;
+
+use borsa_alphavantage::api::dividends::Dividend;
+use borsa_alphavantage::{AvConnector, adapter};
+use borsa_core::{
+    Action, AssetKind, BorsaError, HistoryRequest, Instrument, Interval, connector::HistoryProvider,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use common::{A, bars, equity, usd, utc};
+
+const fn split(ts: DateTime<Utc>, numerator: u32, denominator: u32) -> Action {
+    Action::Split {
+        ts,
+        numerator,
+        denominator,
+    }
+}
+
+/// Weekly bars from April to July 2024 with the given series actions.
+fn weekly(actions: Vec<Action>) -> Arc<dyn adapter::AvHistory> {
+    bars(
+        &[utc(2024, 4, 5, 4), utc(2024, 6, 7, 4), utc(2024, 7, 26, 4)],
+        actions,
+    )
+}
+
+fn nvda_splits() -> Arc<dyn adapter::AvCorporateActions> {
+    <dyn adapter::AvCorporateActions>::from_fns(
+        |_s| {
+            Ok(vec![Dividend {
+                ex_date: NaiveDate::from_ymd_opt(2024, 6, 11).unwrap(),
+                declaration_date: None,
+                record_date: None,
+                payment_date: None,
+                amount: 0.01,
+            }])
+        },
+        |s| {
+            assert_eq!(s, "NVDA");
+            Ok(vec![
+                split(utc(2024, 6, 10, 4), 10, 1),
+                split(utc(2021, 7, 20, 4), 4, 1),
+            ])
+        },
+    )
+}
+
+fn request(interval: Interval, adjusted: bool) -> HistoryRequest {
+    HistoryRequest::builder()
+        .period(utc(2024, 4, 1, 0), utc(2024, 8, 1, 0))
+        .interval(interval)
+        .auto_adjust(adjusted)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn splits_inside_the_window_are_merged_in_time_order() {
+    let dividend = Action::Dividend {
+        ts: utc(2024, 6, 11, 4),
+        amount: usd("0.01"),
+    };
+    let av = AvConnector::from_adapter(&A {
+        h: weekly(vec![dividend.clone()]),
+        c: nvda_splits(),
+    })
+    .with_history_actions(true);
+    let resp = av
+        .history(&equity("NVDA"), request(Interval::W1, true))
+        .await
+        .unwrap();
+    assert_eq!(
+        resp.actions,
+        [split(utc(2024, 6, 10, 4), 10, 1), dividend],
+        "the 2021 split is outside the window; adjusted series keep their own dividends"
+    );
+}
+
+#[tokio::test]
+async fn unadjusted_history_gains_splits_and_dividends() {
+    let av = AvConnector::from_adapter(&A {
+        h: weekly(vec![]),
+        c: nvda_splits(),
+    })
+    .with_history_actions(true);
+    let resp = av
+        .history(&equity("NVDA"), request(Interval::D1, false))
+        .await
+        .unwrap();
+    assert_eq!(resp.actions.len(), 2);
+    assert_eq!(resp.actions[0], split(utc(2024, 6, 10, 4), 10, 1));
+    assert!(matches!(resp.actions[1], Action::Dividend { .. }));
+}
+
+#[tokio::test]
+async fn endpoint_split_replaces_the_series_split_at_the_same_time() {
+    let av = AvConnector::from_adapter(&A {
+        h: weekly(vec![split(utc(2024, 6, 10, 4), 999, 100)]),
+        c: nvda_splits(),
+    })
+    .with_history_actions(true);
+    let resp = av
+        .history(&equity("NVDA"), request(Interval::M1, true))
+        .await
+        .unwrap();
+    assert_eq!(resp.actions, [split(utc(2024, 6, 10, 4), 10, 1)]);
+}
+
+#[tokio::test]
+async fn daily_adjusted_history_keeps_its_own_splits() {
+    let av = AvConnector::from_adapter(&A {
+        h: weekly(vec![]),
+        c: <dyn adapter::AvCorporateActions>::from_fns(|_s| unreachable!(), |_s| unreachable!()),
+    })
+    .with_history_actions(true);
+    let resp = av
+        .history(&equity("NVDA"), request(Interval::D1, true))
+        .await
+        .unwrap();
+    assert!(resp.actions.is_empty());
+}
+
+#[tokio::test]
+async fn split_rate_limits_keep_the_bare_history() {
+    let av = AvConnector::from_adapter(&A {
+        h: weekly(vec![]),
+        c: <dyn adapter::AvCorporateActions>::from_fns(
+            |_s| unreachable!(),
+            |_s| {
+                Err(BorsaError::connector(
+                    "borsa-alphavantage",
+                    "Thank you for using Alpha Vantage! Our standard API call frequency is 5 calls per minute.",
+                ))
+            },
+        ),
+    })
+    .with_history_actions(true);
+    let resp = av
+        .history(&equity("NVDA"), request(Interval::W1, true))
+        .await
+        .unwrap();
+    assert_eq!(resp.candles.len(), 3);
+    assert!(resp.actions.is_empty());
+}
+
+#[tokio::test]
+async fn splits_use_injected_adapter() {
+    let av = AvConnector::from_adapter(&A {
+        h: weekly(vec![]),
+        c: nvda_splits(),
+    })
+    .with_history_actions(true);
+    assert_eq!(av.splits(&equity("NVDA")).await.unwrap().len(), 2);
+
+    let btc = Instrument::from_symbol("BTC", AssetKind::Crypto).unwrap();
+    let err = av.splits(&btc).await.unwrap_err();
+    assert!(matches!(err, BorsaError::Unsupported { .. }), "{err:?}");
+}
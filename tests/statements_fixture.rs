@@ -0,0 +1,162 @@
+use borsa_alphavantage::api::statements::{
+    BalanceSheetResponse, CashFlowResponse, IncomeStatementResponse,
+};
+use borsa_core::{Currency, IsoCurrency, Money, Period};
+use chrono::NaiveDate;
+
+fn amount(m: &Money) -> String {
+    m.amount().to_string()
+}
+
+const fn period(y: i32, m: u32, d: u32) -> Period {
+    Period::Date(NaiveDate::from_ymd_opt(y, m, d).unwrap())
+}
+
+#[test]
+fn income_statement_maps_annual_and_quarterly_reports() {
+    let r: IncomeStatementResponse =
+        serde_json::from_str(include_str!("fixtures/income_statement_ibm.json")).unwrap();
+    assert!(!r.is_empty());
+
+    let annual = &r.reports(false)[0];
+    assert_eq!(
+        annual.cost_of_goods_and_services_sold,
+        Some(27_560_000_000.0)
+    );
+    assert_eq!(annual.investment_income_net, None);
+    assert_eq!(annual.ebitda, Some(12_643_000_000.0));
+    let row = annual.to_row().unwrap();
+    assert_eq!(row.period, period(2023, 12, 31));
+    assert_eq!(
+        row.total_revenue.as_ref().map(amount).as_deref(),
+        Some("61860000000")
+    );
+    assert_eq!(
+        row.gross_profit.as_ref().map(amount).as_deref(),
+        Some("34300000000")
+    );
+    assert_eq!(
+        row.operating_income.as_ref().map(amount).as_deref(),
+        Some("8940000000")
+    );
+    assert_eq!(
+        row.net_income.as_ref().map(amount).as_deref(),
+        Some("7502000000")
+    );
+    assert_eq!(
+        row.net_income.unwrap().currency(),
+        &Currency::Iso(IsoCurrency::USD)
+    );
+
+    let quarterly = r.reports(true);
+    assert_eq!(quarterly.len(), 2);
+    assert_eq!(quarterly[0].ebitda, None);
+    assert_eq!(quarterly[0].to_row().unwrap().period, period(2024, 3, 31));
+}
+
+#[test]
+fn income_statement_keeps_the_reported_currency() {
+    let r: IncomeStatementResponse =
+        serde_json::from_str(include_str!("fixtures/income_statement_sap.json")).unwrap();
+    let row = r.reports(false)[0].to_row().unwrap();
+    assert_eq!(
+        row.total_revenue.unwrap().currency(),
+        &Currency::Iso(IsoCurrency::EUR)
+    );
+    assert!(r.reports(true).is_empty());
+}
+
+#[test]
+fn balance_sheet_maps_totals_cash_debt_and_shares() {
+    let r: BalanceSheetResponse =
+        serde_json::from_str(include_str!("fixtures/balance_sheet_ibm.json")).unwrap();
+
+    let annual = &r.reports(false)[0];
+    assert_eq!(
+        annual.accumulated_depreciation_amortization_ppe,
+        Some(13_455_000_000.0)
+    );
+    assert_eq!(annual.investments, None);
+    let row = annual.to_row().unwrap();
+    assert_eq!(row.period, period(2023, 12, 31));
+    assert_eq!(
+        row.total_assets.as_ref().map(amount).as_deref(),
+        Some("135241000000")
+    );
+    assert_eq!(
+        row.total_liabilities.as_ref().map(amount).as_deref(),
+        Some("112628000000")
+    );
+    assert_eq!(
+        row.total_equity.as_ref().map(amount).as_deref(),
+        Some("22533000000")
+    );
+    assert_eq!(
+        row.cash.as_ref().map(amount).as_deref(),
+        Some("13068000000")
+    );
+    assert_eq!(
+        row.long_term_debt.as_ref().map(amount).as_deref(),
+        Some("50121000000")
+    );
+    assert_eq!(row.shares_outstanding, Some(916_300_000));
+
+    let quarterly = r.reports(true)[0].to_row().unwrap();
+    assert_eq!(quarterly.long_term_debt, None);
+    assert_eq!(quarterly.shares_outstanding, Some(919_700_000));
+}
+
+#[test]
+fn cash_flow_derives_free_cash_flow() {
+    let r: CashFlowResponse =
+        serde_json::from_str(include_str!("fixtures/cash_flow_ibm.json")).unwrap();
+
+    let annual = &r.reports(false)[0];
+    assert_eq!(annual.dividend_payout, Some(6_040_000_000.0));
+    assert_eq!(annual.payments_for_repurchase_of_common_stock, None);
+    assert_eq!(
+        annual.proceeds_from_issuance_of_long_term_debt_and_capital_securities_net,
+        Some(4_558_000_000.0)
+    );
+    let row = annual.to_row().unwrap();
+    assert_eq!(row.period, period(2023, 12, 31));
+    assert_eq!(
+        row.operating_cashflow.as_ref().map(amount).as_deref(),
+        Some("13931000000")
+    );
+    assert_eq!(
+        row.capital_expenditures.as_ref().map(amount).as_deref(),
+        Some("1689000000")
+    );
+    assert_eq!(
+        row.free_cash_flow.as_ref().map(amount).as_deref(),
+        Some("12242000000")
+    );
+    assert_eq!(
+        row.net_income.as_ref().map(amount).as_deref(),
+        Some("7502000000")
+    );
+
+    // Without capital expenditures there is no free cash flow.
+    let quarterly = r.reports(true)[0].to_row().unwrap();
+    assert!(quarterly.operating_cashflow.is_some());
+    assert_eq!(quarterly.free_cash_flow, None);
+}
+
+#[test]
+fn unknown_symbol_decodes_to_empty_statements() {
+    let r: CashFlowResponse = serde_json::from_str("{}").unwrap();
+    assert!(r.is_empty());
+}
+
+#[test]
+fn invalid_fiscal_date_is_a_data_error() {
+    let r: IncomeStatementResponse = serde_json::from_str(
+        r#"{"symbol":"X","annualReports":[{"fiscalDateEnding":"FY2023","reportedCurrency":"USD"}]}"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        r.reports(false)[0].to_row(),
+        Err(borsa_core::BorsaError::Data(_))
+    ));
+}
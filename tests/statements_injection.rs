@@ -0,0 +1,78 @@
+#![cfg(feature = "test-adapters")]
+
+use std::sync::Arc;
+
+use borsa_alphavantage::{AvConnector, adapter};
+use borsa_core::{
+    AssetKind, BorsaError, CashflowRow, IncomeStatementRow, Instrument, Period,
+    connector::{BalanceSheetProvider, CashflowProvider, IncomeStatementProvider},
+};
+
+struct A {
+    f: Arc<dyn adapter::AvFundamentals>,
+}
+impl adapter::CloneArcAdapters for A {
+    fn clone_arc_fundamentals(&self) -> Arc<dyn adapter::AvFundamentals> {
+        self.f.clone()
+    }
+}
+
+fn ibm() -> Instrument {
+    Instrument::from_symbol("IBM", AssetKind::Equity).expect("valid test instrument")
+}
+
+#[tokio::test]
+async fn statements_use_injected_adapter_and_pass_the_frequency() {
+    let f = <dyn adapter::AvFundamentals>::from_fns(
+        |s, quarterly| {
+            assert_eq!(s, "IBM");
+            Ok(vec![IncomeStatementRow {
+                period: if quarterly {
+                    "2024Q1".parse().unwrap()
+                } else {
+                    Period::Year { year: 2023 }
+                },
+                total_revenue: None,
+                gross_profit: None,
+                operating_income: None,
+                net_income: None,
+            }])
+        },
+        |_s, _q| Ok(vec![]),
+        |_s, quarterly| {
+            assert!(!quarterly);
+            Ok(vec![CashflowRow {
+                period: Period::Year { year: 2023 },
+                operating_cashflow: None,
+                capital_expenditures: None,
+                free_cash_flow: None,
+                net_income: None,
+            }])
+        },
+    );
+    let av = AvConnector::from_adapter(&A { f });
+
+    let quarterly = av.income_statement(&ibm(), true).await.unwrap();
+    assert_eq!(quarterly[0].period, "2024Q1".parse().unwrap());
+    let annual = av.income_statement(&ibm(), false).await.unwrap();
+    assert_eq!(annual[0].period, Period::Year { year: 2023 });
+    assert!(av.balance_sheet(&ibm(), true).await.unwrap().is_empty());
+    assert_eq!(av.cashflow(&ibm(), false).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn statement_not_found_is_mapped() {
+    let f = <dyn adapter::AvFundamentals>::from_fns(
+        |_s, _q| Ok(vec![]),
+        |_s, _q| {
+            Err(BorsaError::connector(
+                "borsa-alphavantage",
+                "No data for symbol",
+            ))
+        },
+        |_s, _q| Ok(vec![]),
+    );
+    let av = AvConnector::from_adapter(&A { f });
+    let err = av.balance_sheet(&ibm(), false).await.unwrap_err();
+    assert!(matches!(err, BorsaError::NotFound { .. }), "{err:?}");
+}
@@ -26,12 +26,45 @@ fn av_supported_history_intervals_are_sane() {
     assert!(list.contains(&Interval::D1));
     assert!(list.contains(&Interval::W1));
     assert!(list.contains(&Interval::M1));
+    // Resampled from monthly bars:
+    assert!(list.contains(&Interval::M3));
 
     // Intervals AV does NOT advertise:
     assert!(!list.contains(&Interval::I2m));
     assert!(!list.contains(&Interval::I90m));
     assert!(!list.contains(&Interval::D5));
-    assert!(!list.contains(&Interval::M3));
+}
+
+#[test]
+fn av_supported_history_intervals_follow_kind() {
+    struct A;
+    impl adapter::CloneArcAdapters for A {}
+    let av = AvConnector::from_adapter(&A);
+    let hp = av.as_history_provider().unwrap();
+
+    for kind in [AssetKind::Forex, AssetKind::Crypto] {
+        let list = hp.supported_history_intervals(kind);
+        for i in [
+            Interval::I1m,
+            Interval::I5m,
+            Interval::I15m,
+            Interval::I30m,
+            Interval::I1h,
+            Interval::D1,
+            Interval::W1,
+            Interval::M1,
+            Interval::M3,
+        ] {
+            assert!(list.contains(&i), "{kind:?} should support {i:?}");
+        }
+        assert!(!list.contains(&Interval::I2m));
+        assert!(!list.contains(&Interval::I90m));
+        assert!(!list.contains(&Interval::D5));
+    }
+
+    // Kinds the connector does not serve advertise nothing.
+    assert!(hp.supported_history_intervals(AssetKind::Fund).is_empty());
+    assert!(hp.supported_history_intervals(AssetKind::Bond).is_empty());
 }
 
 #[test]
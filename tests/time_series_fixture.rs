@@ -0,0 +1,210 @@
+use borsa_alphavantage::api::time_series::TimeSeriesResponse;
+use borsa_alphavantage::window::HistoryWindow;
+use borsa_core::{Action, Currency, IsoCurrency, Range};
+use chrono::{TimeZone, Utc};
+
+const USD: Currency = Currency::Iso(IsoCurrency::USD);
+
+fn load() -> TimeSeriesResponse {
+    serde_json::from_str(include_str!("fixtures/intraday_5min.json")).expect("valid fixture")
+}
+
+#[test]
+fn intraday_payload_decodes_series_and_meta() {
+    let ts = load();
+    assert_eq!(ts.time_zone(), Some("US/Eastern"));
+    assert_eq!(ts.bars.len(), 3);
+    let bar = &ts.bars["2009-01-30 16:00:00"];
+    assert!((bar.open - 91.6).abs() < f64::EPSILON);
+    assert_eq!(bar.volume, Some(412_367.0));
+    assert!(bar.adjusted_close.is_none());
+}
+
+#[test]
+fn intraday_payload_maps_to_sorted_candles_in_exchange_time() {
+    let resp = load()
+        .to_history(&USD, &HistoryWindow::Range(Range::Max))
+        .unwrap();
+    assert_eq!(resp.candles.len(), 3);
+    assert!(resp.candles.windows(2).all(|w| w[0].ts < w[1].ts));
+    // 09:30 US/Eastern in winter is 14:30 UTC.
+    assert_eq!(
+        resp.candles[0].ts,
+        Utc.with_ymd_and_hms(2009, 1, 30, 14, 30, 0).unwrap()
+    );
+    assert_eq!(resp.candles[1].volume, Some(412_367));
+    assert_eq!(
+        resp.meta.unwrap().timezone,
+        Some(chrono_tz::Tz::US__Eastern)
+    );
+}
+
+#[test]
+fn intraday_payload_is_trimmed_to_period() {
+    let window = HistoryWindow::Period {
+        start: Utc.with_ymd_and_hms(2009, 1, 30, 14, 0, 0).unwrap(),
+        end: Utc.with_ymd_and_hms(2009, 1, 30, 22, 0, 0).unwrap(),
+    };
+    let resp = load().to_history(&USD, &window).unwrap();
+    assert_eq!(resp.candles.len(), 2);
+}
+
+#[test]
+fn empty_payload_decodes_to_no_bars() {
+    let ts: TimeSeriesResponse = serde_json::from_str("{}").unwrap();
+    assert!(ts.bars.is_empty());
+    assert!(ts.time_zone().is_none());
+}
+
+#[test]
+fn fx_payload_is_priced_in_the_quote_currency() {
+    let ts: TimeSeriesResponse =
+        serde_json::from_str(include_str!("fixtures/fx_daily_eurjpy.json")).unwrap();
+    let jpy = Currency::Iso(IsoCurrency::JPY);
+    let resp = ts
+        .to_history(&jpy, &HistoryWindow::Range(Range::Max))
+        .unwrap();
+    assert_eq!(resp.candles.len(), 2);
+    for c in &resp.candles {
+        assert_eq!(c.open.currency(), &jpy);
+        assert_eq!(c.close.currency(), &jpy);
+        assert!(c.volume.is_none());
+    }
+    assert_eq!(resp.candles[1].close.amount().to_string(), "162");
+}
+
+#[test]
+fn crypto_intraday_payload_is_priced_in_the_market_currency() {
+    let ts: TimeSeriesResponse =
+        serde_json::from_str(include_str!("fixtures/crypto_intraday_5min.json"))
+            .expect("valid fixture");
+    assert_eq!(ts.time_zone(), Some("UTC"));
+    let eur = Currency::Iso(IsoCurrency::EUR);
+    let resp = ts
+        .to_history(&eur, &HistoryWindow::Range(Range::D1))
+        .unwrap();
+    assert_eq!(resp.candles.len(), 3);
+    assert_eq!(
+        resp.candles[0].ts,
+        Utc.with_ymd_and_hms(2024, 5, 3, 12, 0, 0).unwrap()
+    );
+    assert_eq!(resp.candles[2].close.currency(), &eur);
+    assert_eq!(resp.candles[2].volume, Some(118));
+}
+
+fn load_adjusted() -> TimeSeriesResponse {
+    serde_json::from_str(include_str!("fixtures/daily_adjusted_aapl.json")).expect("valid fixture")
+}
+
+#[test]
+fn adjusted_payload_emits_split_actions() {
+    let resp = load_adjusted()
+        .to_history(&USD, &HistoryWindow::Range(Range::Max))
+        .unwrap();
+    assert_eq!(
+        resp.actions,
+        vec![Action::Split {
+            ts: Utc.with_ymd_and_hms(2020, 8, 31, 4, 0, 0).unwrap(),
+            numerator: 4,
+            denominator: 1,
+        }]
+    );
+}
+
+#[test]
+fn adjusted_payload_keeps_the_raw_close() {
+    let resp = load_adjusted()
+        .to_history(&USD, &HistoryWindow::Range(Range::Max))
+        .unwrap();
+    let pre_split = &resp.candles[1];
+    assert_eq!(pre_split.close.amount().to_string(), "124.81");
+    assert_eq!(
+        pre_split.close_unadj.as_ref().unwrap().amount().to_string(),
+        "499.23"
+    );
+    // Unadjusted series leave `close_unadj` empty.
+    let raw = load()
+        .to_history(&USD, &HistoryWindow::Range(Range::Max))
+        .unwrap();
+    assert!(raw.candles.iter().all(|c| c.close_unadj.is_none()));
+}
+
+#[test]
+fn fractional_and_reverse_split_coefficients_become_exact_ratios() {
+    let json = r#"{
+        "Meta Data": { "5. Time Zone": "UTC" },
+        "Time Series (Daily)": {
+            "2021-01-04": { "1. open": "1", "2. high": "1", "3. low": "1", "4. close": "1",
+                            "5. adjusted close": "1", "6. volume": "1", "8. split coefficient": "1.5" },
+            "2021-01-05": { "1. open": "1", "2. high": "1", "3. low": "1", "4. close": "1",
+                            "5. adjusted close": "1", "6. volume": "1", "8. split coefficient": "0.1" },
+            "2021-01-06": { "1. open": "1", "2. high": "1", "3. low": "1", "4. close": "1",
+                            "5. adjusted close": "1", "6. volume": "1", "8. split coefficient": "1.3333" },
+            "2021-01-07": { "1. open": "1", "2. high": "1", "3. low": "1", "4. close": "1",
+                            "5. adjusted close": "1", "6. volume": "1", "8. split coefficient": "0.0067" },
+            "2021-01-08": { "1. open": "1", "2. high": "1", "3. low": "1", "4. close": "1",
+                            "5. adjusted close": "1", "6. volume": "1", "8. split coefficient": "0.005" }
+        }
+    }"#;
+    let ts: TimeSeriesResponse = serde_json::from_str(json).unwrap();
+    let resp = ts
+        .to_history(&USD, &HistoryWindow::Range(Range::Max))
+        .unwrap();
+    let ratios: Vec<(u32, u32)> = resp
+        .actions
+        .iter()
+        .filter_map(|a| match a {
+            Action::Split {
+                numerator,
+                denominator,
+                ..
+            } => Some((*numerator, *denominator)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(ratios, vec![(3, 2), (1, 10), (4, 3), (1, 150), (1, 200)]);
+}
+
+#[test]
+fn digital_currency_daily_payload_decodes_volume_in_the_market_currency() {
+    let ts: TimeSeriesResponse =
+        serde_json::from_str(include_str!("fixtures/digital_currency_daily_btc.json"))
+            .expect("valid fixture");
+    assert_eq!(ts.bars.len(), 2);
+    let eur = Currency::Iso(IsoCurrency::EUR);
+    let resp = ts
+        .to_history(&eur, &HistoryWindow::Range(Range::Max))
+        .unwrap();
+    assert_eq!(
+        resp.candles[0].ts,
+        Utc.with_ymd_and_hms(2024, 5, 2, 0, 0, 0).unwrap()
+    );
+    assert_eq!(resp.candles[1].close.amount().to_string(), "58074.01");
+    assert_eq!(resp.candles[1].close.currency(), &eur);
+    assert_eq!(resp.candles[1].volume, Some(1422));
+    assert!(resp.candles.iter().all(|c| c.close_unadj.is_none()));
+}
+
+#[test]
+fn weekly_adjusted_payload_reads_volume_and_dividends() {
+    let ts: TimeSeriesResponse =
+        serde_json::from_str(include_str!("fixtures/weekly_adjusted_ibm.json"))
+            .expect("valid fixture");
+    let bar = &ts.bars["2024-04-26"];
+    assert_eq!(bar.adjusted_close, Some(165.47));
+    assert_eq!(bar.volume, Some(38_429_577.0));
+    assert_eq!(bar.split_coefficient, None);
+
+    let resp = ts
+        .to_history(&USD, &HistoryWindow::Range(Range::Max))
+        .unwrap();
+    assert!(resp.adjusted);
+    assert_eq!(resp.candles.len(), 3);
+    assert_eq!(
+        resp.actions,
+        vec![Action::Dividend {
+            ts: Utc.with_ymd_and_hms(2024, 2, 9, 5, 0, 0).unwrap(),
+            amount: borsa_core::Money::from_canonical_str("1.66", USD).unwrap(),
+        }]
+    );
+}